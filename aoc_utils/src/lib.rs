@@ -1,3 +1,4 @@
-
 pub mod map;
+pub mod search;
+pub mod solution;
 pub mod utils;
\ No newline at end of file
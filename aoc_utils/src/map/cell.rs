@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter};
+
+/// The error of a `TryFrom<char>` implementation generated by [map_cell](crate::map_cell).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidCell(pub char);
+
+impl Display for InvalidCell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a known map cell", self.0)
+    }
+}
+
+impl std::error::Error for InvalidCell {}
+
+/// Declares an enum of map cells where every variant is annotated with the char it is parsed from and
+/// displayed as, so the map can be read with `Map::parse` and shown with `Display`:
+///
+/// ```
+/// aoc_utils::map_cell! {
+///     #[derive(Debug, Clone, PartialEq)]
+///     pub enum Object {
+///         Empty = '.',
+///         Wall = '#',
+///     }
+/// }
+///
+/// let map = aoc_utils::map::Map::<Object>::parse(".#\n#.").unwrap();
+/// assert_eq!(map.get(&(1, 0)), Some(&Object::Wall));
+/// assert_eq!(format!("{map}"), "\n.#\n#.\n");
+/// ```
+#[macro_export]
+macro_rules! map_cell {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $char:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),*
+        }
+
+        impl ::std::convert::TryFrom<char> for $name {
+            type Error = $crate::map::InvalidCell;
+
+            fn try_from(c: char) -> ::std::result::Result<Self, Self::Error> {
+                match c {
+                    $($char => Ok($name::$variant),)*
+                    _ => Err($crate::map::InvalidCell(c)),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    $($name::$variant => ::std::fmt::Write::write_char(f, $char),)*
+                }
+            }
+        }
+    };
+}
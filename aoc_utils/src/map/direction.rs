@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter, Write};
+use super::Vector;
+
+/// One of the eight compass directions on a map where y grows downwards.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// All directions, clockwise starting with [Direction::Up].
+    pub const ALL: [Direction; 8] = [
+        Direction::Up, Direction::UpRight, Direction::Right, Direction::DownRight,
+        Direction::Down, Direction::DownLeft, Direction::Left, Direction::UpLeft,
+    ];
+
+    /// The directions that move along one axis only, clockwise starting with [Direction::Up].
+    pub const CARDINAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// The directions that move along both axes, clockwise starting with [Direction::UpRight].
+    pub const DIAGONAL: [Direction; 4] = [Direction::UpRight, Direction::DownRight, Direction::DownLeft, Direction::UpLeft];
+
+    /// Turn by 90 degrees to the right.
+    pub fn turn_clockwise(&self) -> Direction {
+        self.turn(90)
+    }
+
+    /// Turn by 90 degrees to the left.
+    pub fn turn_counter_clockwise(&self) -> Direction {
+        self.turn(-90)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn(180)
+    }
+
+    /// Turn clockwise by the given degrees, negative degrees turn counter-clockwise. Only multiples of
+    /// 45 degrees are possible.
+    pub fn turn(&self, degrees: i32) -> Direction {
+        assert_eq!(degrees % 45, 0, "can only turn by multiples of 45 degrees, not {degrees}");
+        let steps = degrees / 45;
+        let index = Direction::ALL.iter().position(|direction| direction == self).expect("all directions are listed");
+        Direction::ALL[(index as i32 + steps).rem_euclid(8) as usize]
+    }
+
+    /// The offset of a single step in this direction.
+    pub fn delta(&self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::UpRight => Vector::new(1, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::DownRight => Vector::new(1, 1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::DownLeft => Vector::new(-1, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Vector {
+        direction.delta()
+    }
+}
+
+/// The cardinal directions are shown like the guard in day 6 (`^>v<`), the diagonals as arrows.
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Up => f.write_char('^'),
+            Direction::UpRight => f.write_char('↗'),
+            Direction::Right => f.write_char('>'),
+            Direction::DownRight => f.write_char('↘'),
+            Direction::Down => f.write_char('v'),
+            Direction::DownLeft => f.write_char('↙'),
+            Direction::Left => f.write_char('<'),
+            Direction::UpLeft => f.write_char('↖'),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Direction;
+    use super::super::Vector;
+
+    #[test]
+    fn turn_around() {
+        assert_eq!(Direction::Up.turn_clockwise(), Direction::Right);
+        assert_eq!(Direction::Left.turn_clockwise(), Direction::Up);
+        assert_eq!(Direction::Up.turn_counter_clockwise(), Direction::Left);
+        assert_eq!(Direction::UpRight.opposite(), Direction::DownLeft);
+        assert_eq!(Direction::Up.turn(45), Direction::UpRight);
+        assert_eq!(Direction::Up.turn(-135), Direction::DownLeft);
+        assert_eq!(Direction::Left.turn(720), Direction::Left);
+    }
+
+    #[test]
+    fn opposite_deltas_cancel_out() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.delta() + direction.opposite().delta(), Vector::default());
+        }
+    }
+
+    #[test]
+    fn display_like_the_guard() {
+        let shown: String = Direction::CARDINAL.iter().map(|direction| direction.to_string()).collect();
+        assert_eq!(shown, "^>v<");
+    }
+}
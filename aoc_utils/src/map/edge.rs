@@ -0,0 +1,47 @@
+use super::{Point, Position};
+
+/// What happens when a step leaves a [Map](super::Map).
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, Default)]
+pub enum Edge {
+    /// The step is not possible, there is nothing beyond the edge.
+    #[default]
+    Bounded,
+    /// The map repeats in all directions, leaving on one side enters on the opposite side (a torus).
+    Wrap,
+    /// The step stops at the last cell before the edge.
+    Clamp,
+}
+
+impl Edge {
+    /// The position on a map of the given size a point ends up at, None if there is none.
+    pub fn apply(&self, point: Point, width: usize, height: usize) -> Option<Position> {
+        let (width, height) = (width as i64, height as i64);
+        match self {
+            Edge::Bounded => point.to_position()
+                .filter(|(x, y)| (*x as i64) < width && (*y as i64) < height),
+            Edge::Wrap => Some((point.x.rem_euclid(width) as usize, point.y.rem_euclid(height) as usize)),
+            Edge::Clamp => Some((point.x.clamp(0, width - 1) as usize, point.y.clamp(0, height - 1) as usize)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_off_the_edge() {
+        let beyond = [Point::new(-1, 0), Point::new(3, 4), Point::new(-7, -1)];
+
+        let bounded: Vec<_> = beyond.iter().map(|point| Edge::Bounded.apply(*point, 3, 2)).collect();
+        assert_eq!(bounded, vec![None, None, None]);
+
+        let wrapped: Vec<_> = beyond.iter().map(|point| Edge::Wrap.apply(*point, 3, 2)).collect();
+        assert_eq!(wrapped, vec![Some((2, 0)), Some((0, 0)), Some((2, 1))]);
+
+        let clamped: Vec<_> = beyond.iter().map(|point| Edge::Clamp.apply(*point, 3, 2)).collect();
+        assert_eq!(clamped, vec![Some((0, 0)), Some((2, 1)), Some((0, 0))]);
+
+        assert_eq!(Edge::default().apply(Point::new(2, 1), 3, 2), Some((2, 1)));
+    }
+}
@@ -0,0 +1,241 @@
+use super::{Coordinate, Direction, Edge, Map, Point, Position, Ray};
+
+/// Rows, columns and diagonals of a map as well as transformed copies and sub-grid views.
+impl <T> Map<T> {
+    /// Iterate all rows from top to bottom, each line going left to right.
+    pub fn rows(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.height).map(|y| self.line((0, y), Direction::Right))
+    }
+
+    /// Iterate all columns from left to right, each line going top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = Line<'_, T>> {
+        (0..self.width).map(|x| self.line((x, 0), Direction::Down))
+    }
+
+    /// Iterate all diagonals going down and to the right, starting with the one in the bottom left corner and
+    /// ending with the one in the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let left_column = (1..self.height).rev().map(|y| (0, y));
+        let top_row = (0..self.width).map(|x| (x, 0));
+        left_column.chain(top_row)
+            .map(|start| self.line(start, Direction::DownRight))
+    }
+
+    /// Iterate all diagonals going down and to the left, starting with the one in the top left corner and
+    /// ending with the one in the bottom right corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Line<'_, T>> {
+        let right_column = (1..self.height).map(|y| (self.width - 1, y));
+        let top_row = (0..self.width).map(|x| (x, 0));
+        top_row.chain(right_column)
+            .map(|start| self.line(start, Direction::DownLeft))
+    }
+
+    /// The line from the start (including it) in the given direction until it leaves the map.
+    fn line(&self, start: Position, direction: Direction) -> Line<'_, T> {
+        Line {
+            map: self,
+            positions: Ray {
+                start,
+                current: Point::from(start) - direction.delta(),
+                step: direction.delta(),
+                width: self.width,
+                height: self.height,
+                edge: Edge::Bounded,
+            },
+        }
+    }
+
+    /// Mirror the map along the diagonal from the top left corner, rows become columns.
+    pub fn transpose(&self) -> Map<T> where T: Clone {
+        self.rearranged(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    /// Rotate the map by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Map<T> where T: Clone {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    /// Mirror the map so left and right are swapped.
+    pub fn flip_horizontal(&self) -> Map<T> where T: Clone {
+        let width = self.width;
+        self.rearranged(self.width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+
+    /// Build a map of the new size where every item is taken from the position `source_fn` gives for it.
+    fn rearranged<F>(&self, width: usize, height: usize, source_fn: F) -> Map<T>
+        where T: Clone, F: Fn(Position) -> Position
+    {
+        let objects = (0..width * height)
+            .map(|index| {
+                let source = source_fn((index % width, index / width));
+                self.get(&source).expect("source position is on the map").clone()
+            })
+            .collect();
+        Map::from_vec(objects, width, height).with_edge(self.edge)
+    }
+
+    /// A view of the part of the map with the origin as top left corner, None if it does not fit on the map.
+    pub fn window(&self, origin: &Position, width: usize, height: usize) -> Option<Window<'_, T>> {
+        let (x, y) = *origin;
+        if width == 0 || height == 0 || x + width > self.width || y + height > self.height {
+            return None
+        }
+        Some(Window { map: self, origin: *origin, width, height })
+    }
+
+    /// Iterate all (overlapping) views of the given size that fit on the map, in reading order of their origin.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Window<'_, T>> {
+        let origins_x = (self.width + 1).saturating_sub(width);
+        let origins_y = (self.height + 1).saturating_sub(height);
+        (0..origins_y)
+            .flat_map(move |y| (0..origins_x).map(move |x| (x, y)))
+            .filter_map(move |origin| self.window(&origin, width, height))
+    }
+}
+
+/// The positions and items along a row, column or diagonal of a map.
+pub struct Line<'m, T> {
+    map: &'m Map<T>,
+    positions: Ray,
+}
+
+impl <'m, T> Iterator for Line<'m, T> {
+    type Item = (Position, &'m T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.positions.next()?;
+        Some((position, self.map.get(&position).expect("lines stay on the map")))
+    }
+}
+
+/// A rectangular part of a map, positions are relative to its top left corner.
+pub struct Window<'m, T> {
+    map: &'m Map<T>,
+    origin: Position,
+    width: usize,
+    height: usize,
+}
+
+impl <'m, T> Window<'m, T> {
+    /// The position of the top left corner on the map.
+    pub fn origin(&self) -> Position {
+        self.origin
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the item at a position relative to the window, None if it is outside the window.
+    pub fn get(&self, position: &impl Coordinate) -> Option<&'m T> {
+        let (x, y) = position.to_position()?;
+        if x >= self.width || y >= self.height {
+            return None
+        }
+        self.map.get(&(self.origin.0 + x, self.origin.1 + y))
+    }
+
+    /// The items of a row of the window as a slice, None if the row is not in the window.
+    pub fn row(&self, y: usize) -> Option<&'m [T]> {
+        if y >= self.height {
+            return None
+        }
+        self.map.row(self.origin.1 + y)
+            .map(|row| &row[self.origin.0..self.origin.0 + self.width])
+    }
+
+    /// Iterate the window in reading order with positions relative to the window.
+    pub fn iter_objects(&self) -> impl Iterator<Item = (Position, &'m T)> + '_ {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| (x, y)))
+            .flat_map(|position| self.get(&position).map(|item| (position, item)))
+    }
+
+    /// Copy the window into a map of its own.
+    pub fn to_map(&self) -> Map<T> where T: Clone {
+        let objects = self.iter_objects()
+            .map(|(_, item)| item.clone())
+            .collect();
+        Map::from_vec(objects, self.width, self.height)
+    }
+}
+
+/// Show the window the same way as a map.
+impl <T> std::fmt::Display for Window<'_, T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("\n")?;
+        for row in (0..self.height).filter_map(|y| self.row(y)) {
+            for item in row {
+                item.fmt(f)?;
+            }
+            f.write_str("\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn letters() -> Map<char> {
+        Map::parse_chars("abc\ndef\nghi", Ok).unwrap()
+    }
+
+    fn as_strings<'m>(lines: impl Iterator<Item = Line<'m, char>>) -> Vec<String> {
+        lines.map(|line| line.map(|(_, c)| *c).collect()).collect()
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let map = letters();
+        assert_eq!(as_strings(map.rows()), vec!["abc", "def", "ghi"]);
+        assert_eq!(as_strings(map.columns()), vec!["adg", "beh", "cfi"]);
+
+        let positions: Vec<_> = map.columns().nth(1).unwrap().map(|(position, _)| position).collect();
+        assert_eq!(positions, vec![(1, 0), (1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn yield_diagonals() {
+        let map = letters();
+        assert_eq!(as_strings(map.diagonals()), vec!["g", "dh", "aei", "bf", "c"]);
+        assert_eq!(as_strings(map.anti_diagonals()), vec!["a", "bd", "ceg", "fh", "i"]);
+
+        let wide = Map::parse_chars("abcd\nefgh", Ok).unwrap();
+        assert_eq!(as_strings(wide.diagonals()), vec!["e", "af", "bg", "ch", "d"]);
+        assert_eq!(as_strings(wide.anti_diagonals()), vec!["a", "be", "cf", "dg", "h"]);
+    }
+
+    #[test]
+    fn transform() {
+        let map = Map::parse_chars("abc\ndef", Ok).unwrap();
+        assert_eq!(format!("{}", map.transpose()), "\nad\nbe\ncf\n");
+        assert_eq!(format!("{}", map.rotate_cw()), "\nda\neb\nfc\n");
+        assert_eq!(format!("{}", map.flip_horizontal()), "\ncba\nfed\n");
+        assert_eq!(format!("{}", map.rotate_cw().rotate_cw().rotate_cw().rotate_cw()), format!("{map}"));
+    }
+
+    #[test]
+    fn sub_grids() {
+        let map = letters();
+
+        let window = map.window(&(1, 1), 2, 2).unwrap();
+        assert_eq!(window.get(&(0, 0)), Some(&'e'));
+        assert_eq!(window.get(&(2, 0)), None);
+        assert_eq!(window.row(1), Some(['h', 'i'].as_slice()));
+        assert_eq!(format!("{window}"), "\nef\nhi\n");
+        assert!(map.window(&(2, 2), 2, 1).is_none());
+
+        let origins: Vec<_> = map.windows(2, 2).map(|window| window.origin()).collect();
+        assert_eq!(origins, vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+        assert_eq!(map.windows(3, 3).count(), 1);
+        assert_eq!(map.windows(4, 1).count(), 0);
+        assert_eq!(format!("{}", map.windows(1, 3).last().unwrap().to_map()), "\nc\nf\ni\n");
+    }
+}
@@ -0,0 +1,643 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+use std::hash::Hash;
+use anyhow::Context;
+
+mod cell;
+mod direction;
+mod edge;
+mod geometry;
+mod point;
+mod sparse;
+
+pub use cell::InvalidCell;
+pub use direction::Direction;
+pub use edge::Edge;
+pub use geometry::{Line, Window};
+pub use point::{Point, Vector};
+pub use sparse::SparseMap;
+
+pub type Position = (usize, usize);
+
+/// Anything that can address a cell of a [Map] or a [SparseMap], i.e. a [Position] or a [Point].
+pub trait Coordinate {
+    /// The position on a map, None if the coordinate can not lie on any map.
+    fn to_position(&self) -> Option<Position>;
+
+    /// The point on a [SparseMap], which has no edges so every coordinate lies on it.
+    fn to_point(&self) -> Point;
+}
+
+impl Coordinate for Position {
+    fn to_position(&self) -> Option<Position> {
+        Some(*self)
+    }
+
+    fn to_point(&self) -> Point {
+        Point::from(*self)
+    }
+}
+
+impl Coordinate for Point {
+    fn to_position(&self) -> Option<Position> {
+        Point::to_position(self)
+    }
+
+    fn to_point(&self) -> Point {
+        *self
+    }
+}
+
+/// Why a [Map] could not be created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapError {
+    /// There are no rows or the rows have no items
+    Empty,
+    /// A row has a different number of items than the first one, rows are counted from 1 like the lines of a
+    /// parsed map
+    Ragged { line: usize, expected: usize, found: usize },
+}
+
+impl Display for MapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapError::Empty => f.write_str("map has no items"),
+            MapError::Ragged { line, expected, found } =>
+                write!(f, "line {line} has {found} items, but the map is {expected} items wide"),
+        }
+    }
+}
+
+impl std::error::Error for MapError {}
+
+/// A rectangular grid of items. The items are stored row by row in a single vector, so a lookup is a single
+/// index computation and cloning a map is a single allocation.
+#[derive(Debug, Clone)]
+pub struct Map<T> {
+    objects: Vec<T>,
+    width: usize,
+    height: usize,
+    edge: Edge,
+}
+
+impl <T> Map<T> {
+
+    /// Create a map from its rows. Panics if the rows are empty or do not have the same length, use
+    /// [Map::try_from_nested_vecs] for input that was not checked yet.
+    pub fn from_nested_vecs(objects: Vec<Vec<T>>) -> Map<T> {
+        Map::try_from_nested_vecs(objects).unwrap_or_else(|error| panic!("invalid map: {error}"))
+    }
+
+    /// Create a map from its rows, which must all have the same length.
+    pub fn try_from_nested_vecs(objects: Vec<Vec<T>>) -> Result<Map<T>, MapError> {
+        let width = objects.first().map_or(0, |row| row.len());
+        let height = objects.len();
+        if width == 0 {
+            return Err(MapError::Empty)
+        }
+        if let Some((y, found)) = objects.iter().map(|row| row.len()).enumerate().find(|(_, len)| *len != width) {
+            return Err(MapError::Ragged { line: y + 1, expected: width, found })
+        }
+
+        Ok(Map::from_vec(objects.into_iter().flatten().collect(), width, height))
+    }
+
+    /// Parse a grid of chars, one row per line, converting every char with the given function. Errors
+    /// report the line and column (both starting at 1) of the offending char.
+    pub fn parse_chars<F>(text: &str, mut parse_fn: F) -> anyhow::Result<Map<T>>
+        where F: FnMut(char) -> anyhow::Result<T>
+    {
+        let rows = text.trim_end_matches(['\n', '\r'])
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| parse_fn(c)
+                        .with_context(|| format!("Invalid char '{c}' at line {}, column {}", y + 1, x + 1)))
+                    .collect::<anyhow::Result<Vec<T>>>()
+            })
+            .collect::<anyhow::Result<Vec<Vec<T>>>>()?;
+
+        match Map::try_from_nested_vecs(rows) {
+            Err(error @ MapError::Ragged { line, .. }) =>
+                Err(anyhow::Error::new(error).context(format!("Invalid row at line {line}"))),
+            result => Ok(result?),
+        }
+    }
+
+    /// Parse a grid of chars with the `TryFrom<char>` implementation of the items, see [Map::parse_chars] and
+    /// [map_cell](crate::map_cell).
+    pub fn parse(text: &str) -> anyhow::Result<Map<T>>
+        where T: TryFrom<char>, T::Error: Into<anyhow::Error>
+    {
+        Map::parse_chars(text, |c| T::try_from(c).map_err(Into::into))
+    }
+
+    /// Create a map from items in reading order.
+    pub fn from_vec(objects: Vec<T>, width: usize, height: usize) -> Map<T> {
+        assert!(width > 0 && height > 0, "a map can not be empty");
+        assert_eq!(objects.len(), width * height, "the items must fill the whole map");
+        Map {
+            objects,
+            width,
+            height,
+            edge: Edge::Bounded,
+        }
+    }
+
+    /// Use the given policy when steps leave the map, the default is [Edge::Bounded].
+    pub fn with_edge(mut self, edge: Edge) -> Map<T> {
+        self.edge = edge;
+        self
+    }
+
+    /// The policy for steps that leave the map.
+    pub fn edge(&self) -> Edge {
+        self.edge
+    }
+
+    /// Number of items in a row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn with_size(size_x: usize, size_y: usize, fill_item: T) -> Map<T> where T: Clone {
+        Map::from_vec(vec![fill_item; size_x * size_y], size_x, size_y)
+    }
+
+    /// The index of the position in the underlying storage, None if the position is not on the map.
+    pub fn to_index(&self, position: &impl Coordinate) -> Option<usize> {
+        let (x, y) = position.to_position()?;
+        if x < self.width && y < self.height {
+            Some(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The position of an index in the underlying storage.
+    pub fn to_position(&self, index: usize) -> Position {
+        (index % self.width, index / self.width)
+    }
+
+    /// get reference to item at given position
+    pub fn get(&self, position: &impl Coordinate) -> Option<&T> {
+        self.to_index(position)
+            .map(|index| &self.objects[index])
+    }
+
+    /// get mutable reference to item at given position
+    pub fn get_mut(&mut self, position: &impl Coordinate) -> Option<&mut T> {
+        self.to_index(position)
+            .map(|index| &mut self.objects[index])
+    }
+
+    /// The items of a row as a slice, None if the row is not on the map.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.objects.chunks_exact(self.width).nth(y)
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        self.objects.chunks_exact_mut(self.width).nth(y)
+    }
+
+    /// Iterate the items of a column from top to bottom, the iterator is empty if the column is not on the map.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let items = if x < self.width { &self.objects[x..] } else { &[] };
+        items.iter().step_by(self.width)
+    }
+
+    /// check if the given position lies on the map
+    pub fn contains(&self, position: &impl Coordinate) -> bool {
+        position.to_position()
+            .is_some_and(|(x, y)| x < self.width && y < self.height)
+    }
+
+    pub fn set(&mut self, position: &impl Coordinate, item: T)  {
+        let cell_ref = self.get_mut(position).unwrap();
+        *cell_ref = item
+    }
+
+    /// Calculate the new position if walking from the given position in given direction.
+    /// Returns None if the step would leave a bounded map, see [Map::with_edge].
+    pub fn new_position(&self, position: &Position, direction: &Direction) -> Option<Position> {
+        self.new_position_with(position, *direction, self.edge)
+    }
+
+    /// Calculate the new position after a step of a direction or vector, using the given edge policy instead
+    /// of the one of the map.
+    pub fn new_position_with(&self, position: &Position, step: impl Into<Vector>, edge: Edge) -> Option<Position> {
+        edge.apply(Point::from(*position) + step.into(), self.width, self.height)
+    }
+
+    /// Iterate the up to 4 neighbors in the cardinal directions that lie on the map.
+    pub fn neighbors<'m>(&'m self, position: &Position) -> impl Iterator<Item = (Position, Direction, &'m T)> + 'm {
+        self.neighbors_with(position, &Direction::CARDINAL)
+    }
+
+    /// Iterate the up to 8 neighbors, including the diagonal ones, that lie on the map.
+    pub fn neighbors_8<'m>(&'m self, position: &Position) -> impl Iterator<Item = (Position, Direction, &'m T)> + 'm {
+        self.neighbors_with(position, &Direction::ALL)
+    }
+
+    /// Iterate the neighbors in the given directions that lie on the map, in the order of the directions. On a
+    /// map that wraps or clamps at the edges a position is never its own neighbor, and if several directions
+    /// reach the same cell (e.g. up and down on a wrapping map that is 2 rows high) it is only yielded for the
+    /// first of them.
+    pub fn neighbors_with<'m>(&'m self, position: &Position, directions: &'m [Direction])
+        -> impl Iterator<Item = (Position, Direction, &'m T)> + 'm
+    {
+        let position = *position;
+        let mut seen = Vec::with_capacity(directions.len());
+        directions.iter().filter_map(move |direction| {
+            let neighbor_position = self.new_position(&position, direction)
+                .filter(|neighbor_position| *neighbor_position != position && !seen.contains(neighbor_position))?;
+            seen.push(neighbor_position);
+            Some((neighbor_position, *direction, self.get(&neighbor_position)?))
+        })
+    }
+
+    /// Iterate the positions on a straight line from the given position (excluding it) in steps of a direction or
+    /// vector, until the line leaves the map. The ray does not borrow the map, so it can be modified while walking.
+    /// The ray follows the edge policy of the map, which can be changed with [Ray::with_edge]. On a map that
+    /// wraps the ray only ends if it returns to the start, with clamping it ends when it can't move any more.
+    pub fn ray_positions(&self, position: &Position, step: impl Into<Vector>) -> Ray {
+        let step = step.into();
+        assert_ne!(step, Vector::default(), "a ray needs a step that moves");
+        Ray {
+            start: *position,
+            current: Point::from(*position),
+            step,
+            width: self.width,
+            height: self.height,
+            edge: self.edge,
+        }
+    }
+
+    /// Iterate the positions and items on a straight line, see [Map::ray_positions].
+    pub fn ray<'m>(&'m self, position: &Position, step: impl Into<Vector>) -> impl Iterator<Item = (Position, &'m T)> + 'm {
+        self.ray_positions(position, step)
+            .map(|position| (position, self.get(&position).expect("rays stay on the map")))
+    }
+
+    /// Walk along a ray until the predicate matches and return that position and its item. Returns None if the
+    /// ray leaves the map before.
+    pub fn cast_until<P>(&self, position: &Position, step: impl Into<Vector>, mut predicate: P) -> Option<(Position, &T)>
+        where P: FnMut(&Position, &T) -> bool
+    {
+        self.ray(position, step)
+            .find(|(position, item)| predicate(position, item))
+    }
+
+    /// Create an iterator that walks the map in reading order
+    pub fn iter_objects(&self) -> PositionIterator<'_, T> {
+        PositionIterator::for_map(self)
+    }
+
+    /// Create an iterator that walks the map in reading order and allows to modify the items
+    pub fn iter_objects_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> {
+        let width = self.width;
+        self.objects.iter_mut()
+            .enumerate()
+            .map(move |(index, item)| ((index % width, index / width), item))
+    }
+
+    /// Create a new map of the same size by converting every item.
+    pub fn map<U, F>(&self, mut convert_fn: F) -> Map<U> where F: FnMut(Position, &T) -> U {
+        let objects = self.iter_objects()
+            .map(|(position, item)| convert_fn(position, item))
+            .collect();
+        Map::from_vec(objects, self.width, self.height).with_edge(self.edge)
+    }
+
+    /// Put a new item at the position and return the one that was there. Panics if the position is not on the map.
+    pub fn replace(&mut self, position: &impl Coordinate, item: T) -> T {
+        std::mem::replace(self.get_mut(position).expect("position must be on the map"), item)
+    }
+
+    /// Exchange the items at both positions. Panics if a position is not on the map.
+    pub fn swap(&mut self, a: &impl Coordinate, b: &impl Coordinate) {
+        let a = self.to_index(a).expect("position must be on the map");
+        let b = self.to_index(b).expect("position must be on the map");
+        self.objects.swap(a, b)
+    }
+
+    /// Flood fill: replace the item at the start and all items that are equal to it and connected to it in the
+    /// cardinal directions. Returns the number of replaced items.
+    pub fn fill_region(&mut self, start: &Position, item: T) -> usize where T: PartialEq + Clone {
+        let Some(region_item) = self.get(start).cloned() else {
+            return 0
+        };
+
+        let mut visited = Map::with_size(self.width, self.height, false);
+        visited.set(start, true);
+        let mut to_fill = vec![*start];
+        let mut filled = 0;
+
+        while let Some(current) = to_fill.pop() {
+            self.set(&current, item.clone());
+            filled += 1;
+
+            for (neighbor_position, _, neighbor) in self.neighbors(&current) {
+                if *neighbor == region_item && !visited.replace(&neighbor_position, true) {
+                    to_fill.push(neighbor_position);
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// The first item in reading order that matches the predicate, together with its position.
+    pub fn find<P>(&self, mut predicate: P) -> Option<(Position, &T)> where P: FnMut(&T) -> bool {
+        self.iter_objects().find(|(_, item)| predicate(item))
+    }
+
+    /// All items that match the predicate together with their positions, in reading order.
+    pub fn find_all<'m, P>(&'m self, mut predicate: P) -> impl Iterator<Item = (Position, &'m T)> + 'm
+        where P: FnMut(&T) -> bool + 'm
+    {
+        self.iter_objects().filter(move |(_, item)| predicate(item))
+    }
+
+    /// The position of the first item in reading order that is equal to the given one.
+    pub fn position_of(&self, item: &T) -> Option<Position> where T: PartialEq {
+        self.find(|other| other == item).map(|(position, _)| position)
+    }
+
+    /// Group the positions of all items by a key, items for which the key function returns None are skipped.
+    /// The positions of a group are in reading order.
+    pub fn group_positions_by<'m, K, F>(&'m self, mut key_fn: F) -> HashMap<K, Vec<Position>>
+        where K: Eq + Hash, F: FnMut(&'m T) -> Option<K>
+    {
+        let mut groups: HashMap<K, Vec<Position>> = HashMap::new();
+        for (position, item) in self.iter_objects() {
+            if let Some(key) = key_fn(item) {
+                groups.entry(key).or_default().push(position);
+            }
+        }
+        groups
+    }
+
+    /// Number of items that match the predicate.
+    pub fn count<P>(&self, mut predicate: P) -> usize where P: FnMut(&T) -> bool {
+        self.objects.iter().filter(|item| predicate(item)).count()
+    }
+}
+
+// nice map display
+impl <T> Display for Map<T> where T: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char('\n')?;
+        for row in self.objects.chunks_exact(self.width) {
+            for object in row {
+                object.fmt(f)?
+            }
+            f.write_char('\n')?
+        }
+        Ok(())
+    }
+}
+
+pub struct PositionIterator<'m, T> {
+    map: &'m Map<T>,
+    index: usize,
+}
+
+impl <T> PositionIterator<'_, T> {
+    fn for_map(map: & Map<T>) -> PositionIterator<'_, T> {
+        PositionIterator {
+            map,
+            index: 0,
+        }
+    }
+}
+
+impl <'m, T> Iterator for PositionIterator<'m, T> {
+    type Item = (Position, &'m T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.map.objects.get(self.index)?;
+        let result = (self.map.to_position(self.index), item);
+        self.index += 1;
+        Some(result)
+    }
+}
+
+/// Positions along a straight line on a map, see [Map::ray_positions].
+pub struct Ray {
+    start: Position,
+    current: Point,
+    step: Vector,
+    width: usize,
+    height: usize,
+    edge: Edge,
+}
+
+impl Ray {
+    /// Use the given policy instead of the one of the map when the ray reaches the edge.
+    pub fn with_edge(mut self, edge: Edge) -> Ray {
+        self.edge = edge;
+        self
+    }
+}
+
+impl Iterator for Ray {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.edge.apply(self.current + self.step, self.width, self.height)?;
+        if (self.edge == Edge::Wrap && next == self.start) || Point::from(next) == self.current {
+            // went around once or got stuck at the edge
+            return None
+        }
+        self.current = Point::from(next);
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{Result, Context};
+    use super::*;
+
+    crate::map_cell! {
+        #[derive(Debug)]
+        pub enum Object {
+            Empty = '.',
+            Something = '#',
+        }
+    }
+
+    fn parse(raw_data: &str) -> Result<Map<Object>> {
+        Map::parse(raw_data)
+    }
+
+    fn get_test_map() -> &'static str {
+       ".....\n\
+       .#.#.\n\
+       ..#..\n\
+       .#.#.\n\
+       ....."
+    }
+
+    #[test]
+    fn round_trip_parsing_and_display() {
+        let result = parse(get_test_map()).expect("should parse");
+        assert_eq!(format!("{}", result), format!("\n{}\n", get_test_map()));
+    }
+
+    #[test]
+    fn access_with_points() {
+        let map = parse(get_test_map()).expect("should parse");
+
+        assert!(matches!(map.get(&Point::new(1, 1)), Some(Object::Something)));
+        assert!(map.get(&Point::new(-1, 1)).is_none());
+        assert!(map.contains(&Point::new(4, 4)));
+        assert!(!map.contains(&Point::new(5, 4)));
+        assert!(!map.contains(&Point::new(0, -1)));
+    }
+
+    #[test]
+    fn iterate_neighbors() {
+        let map = parse(get_test_map()).expect("should parse");
+
+        let corner: Vec<_> = map.neighbors(&(0, 0)).map(|(position, direction, _)| (position, direction)).collect();
+        assert_eq!(corner, vec![((1, 0), Direction::Right), ((0, 1), Direction::Down)]);
+
+        let around_center = map.neighbors_8(&(2, 2))
+            .filter(|(_, _, object)| matches!(object, Object::Something))
+            .count();
+        assert_eq!(around_center, 4);
+
+        let diagonals: Vec<_> = map.neighbors_with(&(0, 4), &Direction::DIAGONAL).map(|(position, _, _)| position).collect();
+        assert_eq!(diagonals, vec![(1, 3)]);
+    }
+
+    #[test]
+    fn cast_rays() {
+        let map = parse(get_test_map()).expect("should parse");
+
+        let diagonal: Vec<_> = map.ray_positions(&(0, 0), Direction::DownRight).collect();
+        assert_eq!(diagonal, vec![(1, 1), (2, 2), (3, 3), (4, 4)]);
+
+        let jumps: Vec<_> = map.ray_positions(&(4, 0), Vector::new(-2, 1)).collect();
+        assert_eq!(jumps, vec![(2, 1), (0, 2)]);
+
+        assert_eq!(map.ray(&(0, 1), Direction::Right).count(), 4);
+        assert_eq!(map.cast_until(&(0, 1), Direction::Right, |_, object| matches!(object, Object::Something)).map(|(position, _)| position), Some((1, 1)));
+        assert!(map.cast_until(&(0, 0), Direction::Right, |_, object| matches!(object, Object::Something)).is_none());
+    }
+
+    #[test]
+    fn flat_storage() {
+        let map = Map::from_nested_vecs(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+        assert_eq!(map.to_index(&(2, 1)), Some(5));
+        assert_eq!(map.to_index(&(3, 0)), None);
+        assert_eq!(map.to_position(4), (1, 1));
+        assert_eq!(map.row(1), Some([4, 5, 6].as_slice()));
+        assert_eq!(map.row(2), None);
+        assert_eq!(map.column(1).collect::<Vec<_>>(), vec![&2, &5]);
+        assert_eq!(map.column(3).count(), 0);
+        assert_eq!(map.iter_objects().last(), Some(((2, 1), &6)));
+    }
+
+    #[test]
+    fn reject_empty_and_ragged_maps() {
+        assert_eq!(Map::<u8>::try_from_nested_vecs(vec![]).unwrap_err(), MapError::Empty);
+        assert_eq!(Map::<u8>::try_from_nested_vecs(vec![vec![]]).unwrap_err(), MapError::Empty);
+        assert_eq!(
+            Map::try_from_nested_vecs(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err(),
+            MapError::Ragged { line: 3, expected: 2, found: 1 }
+        );
+
+        let map = Map::try_from_nested_vecs(vec![vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap();
+        assert_eq!((map.width(), map.height()), (2, 3));
+    }
+
+    #[test]
+    fn report_invalid_chars() {
+        let error = parse(".....\n..x..").unwrap_err();
+        assert_eq!(error.to_string(), "Invalid char 'x' at line 2, column 3");
+        assert_eq!(error.root_cause().to_string(), "'x' is not a known map cell");
+
+        let digits = Map::parse_chars("12\n34\n", |c| c.to_digit(10).context("not a digit")).unwrap();
+        assert_eq!(digits.get(&(1, 1)), Some(&4));
+        assert!(Map::parse_chars("12\n3", |c| c.to_digit(10).context("not a digit")).is_err());
+
+        let error = Map::parse_chars("ab\n\ncd", Ok).unwrap_err();
+        assert_eq!(error.to_string(), "Invalid row at line 2");
+        assert_eq!(error.root_cause().to_string(), "line 2 has 0 items, but the map is 2 items wide");
+    }
+
+    #[test]
+    fn locate_items() {
+        let map = Map::parse_chars("a.b\n.ba\nc..", Ok).unwrap();
+
+        assert_eq!(map.find(|c| *c == 'b'), Some(((2, 0), &'b')));
+        assert_eq!(map.find(|c| *c == 'x'), None);
+        assert_eq!(map.find_all(|c| *c == 'a').map(|(position, _)| position).collect::<Vec<_>>(), vec![(0, 0), (2, 1)]);
+        assert_eq!(map.position_of(&'c'), Some((0, 2)));
+        assert_eq!(map.count(|c| *c == '.'), 4);
+
+        let groups = map.group_positions_by(|c| c.is_alphabetic().then_some(*c));
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&'b'], vec![(2, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn modify_in_place() {
+        let mut map = Map::parse_chars("aab\nbab\nbbb", Ok).unwrap();
+
+        for (position, item) in map.iter_objects_mut() {
+            if position == (0, 0) {
+                *item = 'x';
+            }
+        }
+        assert_eq!(map.replace(&(0, 0), 'a'), 'x');
+
+        map.swap(&(0, 0), &(0, 1));
+        assert_eq!(map.row(0), Some(['b', 'a', 'b'].as_slice()));
+
+        // the b in the top left corner is not connected to the others
+        assert_eq!(map.fill_region(&(0, 2), '.'), 5);
+        assert_eq!(format!("{map}"), "\nba.\naa.\n...\n");
+        assert_eq!(map.fill_region(&(0, 1), 'a'), 3);
+
+        let heights = map.map(|(x, y), item| if *item == '.' { x + y } else { 0 });
+        assert_eq!(heights.get(&(2, 2)), Some(&4));
+        assert_eq!(heights.count(|height| *height == 0), 4);
+    }
+
+    #[test]
+    fn edge_policies() {
+        let map = Map::parse_chars("abc\ndef", Ok).unwrap();
+        assert_eq!(map.new_position(&(0, 0), &Direction::Left), None);
+        assert_eq!(map.new_position_with(&(0, 0), Direction::Left, Edge::Wrap), Some((2, 0)));
+        assert_eq!(map.new_position_with(&(0, 1), Vector::new(-2, 3), Edge::Clamp), Some((0, 1)));
+
+        let torus = map.clone().with_edge(Edge::Wrap);
+        let around_corner: Vec<_> = torus.neighbors(&(0, 0)).map(|(_, _, c)| *c).collect();
+        // up and down both wrap to the same cell
+        assert_eq!(around_corner, vec!['d', 'b', 'c']);
+        let tiny_torus = Map::parse_chars("ab\ncd", Ok).unwrap().with_edge(Edge::Wrap);
+        let neighbors: Vec<_> = tiny_torus.neighbors(&(0, 0)).map(|(_, direction, c)| (direction, *c)).collect();
+        assert_eq!(neighbors, vec![(Direction::Up, 'c'), (Direction::Right, 'b')]);
+        let wrapping_ray: Vec<_> = torus.ray(&(1, 0), Direction::Right).map(|(_, c)| *c).collect();
+        assert_eq!(wrapping_ray, vec!['c', 'a']);
+        let bounded_ray: Vec<_> = torus.ray_positions(&(1, 0), Direction::Right).with_edge(Edge::Bounded).collect();
+        assert_eq!(bounded_ray, vec![(2, 0)]);
+
+        let clamped = map.with_edge(Edge::Clamp);
+        assert_eq!(clamped.neighbors(&(0, 0)).count(), 2);
+        let sliding_ray: Vec<_> = clamped.ray_positions(&(0, 0), Direction::DownRight).collect();
+        assert_eq!(sliding_ray, vec![(1, 1), (2, 1)]);
+    }
+}
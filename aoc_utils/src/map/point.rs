@@ -0,0 +1,175 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use super::Position;
+
+/// A point with signed coordinates. Unlike a [Position] it can lie outside of a map, so offsets can be added
+/// freely and the result is checked only once, e.g. with [Point::to_position] or `Map::contains`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// The signed offset between two points.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Vector {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /// The position of the point, or None if it has a negative coordinate.
+    pub fn to_position(&self) -> Option<Position> {
+        Some((usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?))
+    }
+
+    /// Number of steps between the points when only moving horizontally or vertically.
+    pub fn manhattan_distance(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Number of steps between the points when diagonal moves are allowed as well.
+    pub fn chebyshev_distance(&self, other: &Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+}
+
+impl Vector {
+    pub const fn new(x: i64, y: i64) -> Vector {
+        Vector { x, y }
+    }
+}
+
+impl From<Position> for Point {
+    fn from((x, y): Position) -> Point {
+        Point { x: x as i64, y: y as i64 }
+    }
+}
+
+impl TryFrom<Point> for Position {
+    type Error = Point;
+
+    /// Fails with the point itself if it has a negative coordinate.
+    fn try_from(point: Point) -> Result<Position, Point> {
+        point.to_position().ok_or(point)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point { x: self.x + vector.x, y: self.y + vector.y }
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, vector: Vector) {
+        *self = *self - vector
+    }
+}
+
+/// The vector that leads from the other point to this one.
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, other: Vector) -> Vector {
+        self + -other
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector { x: -self.x, y: -self.y }
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: i64) -> Vector {
+        Vector { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Vector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{}, {}>", self.x, self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Point, Vector};
+
+    #[test]
+    fn vector_arithmetic() {
+        let a = Point::new(4, 3);
+        let b = Point::new(5, 5);
+
+        let delta = b - a;
+        assert_eq!(delta, Vector::new(1, 2));
+        assert_eq!(b + delta, Point::new(6, 7));
+        assert_eq!(a - delta * 4, Point::new(0, -5));
+        assert_eq!(delta + -delta, Vector::default());
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(-1, 2);
+        let b = Point::new(3, -4);
+
+        assert_eq!(a.manhattan_distance(&b), 10);
+        assert_eq!(a.chebyshev_distance(&b), 6);
+    }
+
+    #[test]
+    fn convert_positions() {
+        assert_eq!(Point::from((3, 7)), Point::new(3, 7));
+        assert_eq!(Point::new(3, 7).to_position(), Some((3, 7)));
+        assert_eq!(Point::new(-1, 7).to_position(), None);
+        assert_eq!(<(usize, usize)>::try_from(Point::new(0, -1)), Err(Point::new(0, -1)));
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Write};
+use super::{Coordinate, Direction, Map, Point};
+
+/// A grid without fixed size that only stores the occupied cells. The coordinates can be negative and the
+/// bounding box grows with the items that are set, so there is no need to know the size up front.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMap<T> {
+    objects: HashMap<Point, T>,
+    /// The bounding box, grown with every item that is set. It is only recalculated if an item on its border
+    /// is removed.
+    bounds: Option<(Point, Point)>,
+}
+
+impl <T> Default for SparseMap<T> {
+    fn default() -> Self {
+        SparseMap::new()
+    }
+}
+
+impl <T> SparseMap<T> {
+    /// The char [Display] uses for cells without an item.
+    pub const EMPTY_CELL: char = '.';
+
+    pub fn new() -> SparseMap<T> {
+        SparseMap {
+            objects: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.objects.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.objects.is_empty()
+    }
+
+    /// The top left and bottom right corner (both inclusive) of the smallest rectangle that contains all items,
+    /// None if the map is empty.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    fn recalculate_bounds(&mut self) {
+        self.bounds = self.objects.keys().fold(None, grow_bounds);
+    }
+
+    /// get reference to item at given position, None if the cell is empty
+    pub fn get(&self, position: &impl Coordinate) -> Option<&T> {
+        self.objects.get(&position.to_point())
+    }
+
+    /// get mutable reference to item at given position, None if the cell is empty
+    pub fn get_mut(&mut self, position: &impl Coordinate) -> Option<&mut T> {
+        self.objects.get_mut(&position.to_point())
+    }
+
+    /// check if there is an item at the given position
+    pub fn contains(&self, position: &impl Coordinate) -> bool {
+        self.objects.contains_key(&position.to_point())
+    }
+
+    pub fn set(&mut self, position: &impl Coordinate, item: T) {
+        let point = position.to_point();
+        self.bounds = grow_bounds(self.bounds, &point);
+        self.objects.insert(point, item);
+    }
+
+    /// Empty the cell and return the item that was there.
+    pub fn remove(&mut self, position: &impl Coordinate) -> Option<T> {
+        let point = position.to_point();
+        let item = self.objects.remove(&point);
+        if item.is_some() && is_on_border(self.bounds, &point) {
+            self.recalculate_bounds();
+        }
+        item
+    }
+
+    /// Only keep the items the predicate accepts.
+    pub fn retain<P>(&mut self, mut predicate: P) where P: FnMut(&Point, &T) -> bool {
+        let bounds = self.bounds;
+        let mut removed_from_border = false;
+        self.objects.retain(|point, item| {
+            let keep = predicate(point, item);
+            removed_from_border |= !keep && is_on_border(bounds, point);
+            keep
+        });
+        if removed_from_border {
+            self.recalculate_bounds();
+        }
+    }
+
+    /// Calculate the new position if walking from the given position in given direction. Same signature as
+    /// [Map::new_position], but this is always Some because the map has no edges.
+    pub fn new_position(&self, position: &Point, direction: &Direction) -> Option<Point> {
+        Some(*position + direction.delta())
+    }
+
+    /// Iterate the neighbors in the cardinal directions, the item is None for empty cells.
+    pub fn neighbors<'m>(&'m self, position: &Point) -> impl Iterator<Item = (Point, Direction, Option<&'m T>)> + 'm {
+        self.neighbors_with(position, &Direction::CARDINAL)
+    }
+
+    /// Iterate the neighbors, including the diagonal ones, the item is None for empty cells.
+    pub fn neighbors_8<'m>(&'m self, position: &Point) -> impl Iterator<Item = (Point, Direction, Option<&'m T>)> + 'm {
+        self.neighbors_with(position, &Direction::ALL)
+    }
+
+    /// Iterate the neighbors in the given directions, in the order of the directions. Empty cells are included
+    /// with None as item, filter on the item to only get the occupied ones.
+    pub fn neighbors_with<'m>(&'m self, position: &Point, directions: &'m [Direction])
+        -> impl Iterator<Item = (Point, Direction, Option<&'m T>)> + 'm
+    {
+        let position = *position;
+        directions.iter().filter_map(move |direction| {
+            let neighbor_position = self.new_position(&position, direction)?;
+            Some((neighbor_position, *direction, self.get(&neighbor_position)))
+        })
+    }
+
+    /// Iterate all occupied cells in no particular order.
+    pub fn iter_objects(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.objects.iter().map(|(point, item)| (*point, item))
+    }
+
+    /// Create a dense map of the bounding box, empty cells are filled with the given item. The top left corner
+    /// of the bounding box becomes `(0, 0)`. None if the map is empty.
+    pub fn to_map(&self, fill_item: T) -> Option<Map<T>> where T: Clone {
+        let (min, max) = self.bounds()?;
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+
+        let mut map = Map::with_size(width, height, fill_item);
+        for (point, item) in self.iter_objects() {
+            map.set(&Point::new(point.x - min.x, point.y - min.y), item.clone());
+        }
+        Some(map)
+    }
+}
+
+/// Every cell of a dense map becomes an item at the same position, use [SparseMap::retain] to drop the
+/// empty ones.
+impl <T> From<Map<T>> for SparseMap<T> {
+    fn from(map: Map<T>) -> SparseMap<T> {
+        let width = map.width;
+        let objects = map.objects.into_iter()
+            .enumerate()
+            .map(|(index, item)| (Point::from((index % width, index / width)), item))
+            .collect();
+        let mut sparse_map = SparseMap { objects, bounds: None };
+        sparse_map.recalculate_bounds();
+        sparse_map
+    }
+}
+
+/// Whether the point is on the border of the bounds, removing it might shrink them.
+fn is_on_border(bounds: Option<(Point, Point)>, point: &Point) -> bool {
+    bounds.is_some_and(|(min, max)| point.x == min.x || point.x == max.x || point.y == min.y || point.y == max.y)
+}
+
+/// The bounds that also contain the point.
+fn grow_bounds(bounds: Option<(Point, Point)>, point: &Point) -> Option<(Point, Point)> {
+    match bounds {
+        None => Some((*point, *point)),
+        Some((min, max)) => Some((
+            Point::new(min.x.min(point.x), min.y.min(point.y)),
+            Point::new(max.x.max(point.x), max.y.max(point.y)),
+        )),
+    }
+}
+
+/// Shows the bounding box like a [Map], empty cells are shown as [SparseMap::EMPTY_CELL].
+impl <T> Display for SparseMap<T> where T: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char('\n')?;
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    match self.get(&Point::new(x, y)) {
+                        Some(item) => item.fmt(f)?,
+                        None => f.write_char(Self::EMPTY_CELL)?,
+                    }
+                }
+                f.write_char('\n')?
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grow_in_all_directions() {
+        let mut map = SparseMap::new();
+        assert_eq!(map.bounds(), None);
+        assert_eq!(format!("{map}"), "\n");
+
+        map.set(&(1, 1), 'a');
+        map.set(&Point::new(-1, 0), 'b');
+        map.set(&Point::new(2, -1), 'c');
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&Point::new(-1, 0)), Some(&'b'));
+        assert_eq!(map.get(&(0, 0)), None);
+        assert_eq!(map.bounds(), Some((Point::new(-1, -1), Point::new(2, 1))));
+        assert_eq!(format!("{map}"), "\n...c\nb...\n..a.\n");
+
+        assert_eq!(map.remove(&Point::new(2, -1)), Some('c'));
+        assert_eq!(map.bounds(), Some((Point::new(-1, 0), Point::new(1, 1))));
+        map.set(&(3, 0), 'd');
+        assert_eq!(map.bounds(), Some((Point::new(-1, 0), Point::new(3, 1))));
+        map.retain(|point, _| point.x >= 0);
+        assert_eq!(map.bounds(), Some((Point::new(1, 0), Point::new(3, 1))));
+    }
+
+    #[test]
+    fn neighbors_beyond_the_edges() {
+        let mut map = SparseMap::new();
+        map.set(&(0, 0), 1);
+        map.set(&Point::new(0, -1), 2);
+        map.set(&Point::new(-1, -1), 3);
+
+        let occupied: Vec<_> = map.neighbors(&Point::new(0, 0))
+            .filter_map(|(_, direction, item)| Some((direction, *item?)))
+            .collect();
+        assert_eq!(occupied, vec![(Direction::Up, 2)]);
+        assert_eq!(map.neighbors(&Point::new(0, 0)).count(), 4);
+        assert_eq!(map.neighbors_8(&Point::new(0, 0)).filter(|(_, _, item)| item.is_some()).count(), 2);
+        assert_eq!(map.new_position(&Point::new(0, 0), &Direction::Left), Some(Point::new(-1, 0)));
+    }
+
+    #[test]
+    fn convert_from_and_to_map() {
+        let map = Map::parse_chars("..#\n#..\n...", Ok).unwrap();
+
+        let mut sparse = SparseMap::from(map);
+        sparse.retain(|_, c| *c == '#');
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get(&(2, 0)), Some(&'#'));
+
+        let dense = sparse.to_map('.').unwrap();
+        assert_eq!((dense.width(), dense.height()), (3, 2));
+        assert_eq!(format!("{dense}"), format!("{sparse}"));
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The result of a [bfs]: the distance of every reached state to the nearest start and the state it was reached
+/// from.
+#[derive(Debug, Clone)]
+pub struct Bfs<N> {
+    distances: HashMap<N, usize>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+/// Breadth first search from one or more starts. Stops as soon as a state is reached that `is_goal` accepts,
+/// otherwise it continues until every reachable state has been visited.
+pub fn bfs<N, S, I, G>(starts: impl IntoIterator<Item = N>, mut successors: S, mut is_goal: G) -> Bfs<N>
+    where N: Hash + Eq + Clone, S: FnMut(&N) -> I, I: IntoIterator<Item = N>, G: FnMut(&N) -> bool
+{
+    let mut result = Bfs {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+        goal: None,
+    };
+
+    let mut to_explore = VecDeque::new();
+    for start in starts {
+        if result.distances.contains_key(&start) {
+            continue
+        }
+        result.distances.insert(start.clone(), 0);
+        if is_goal(&start) {
+            result.goal = Some(start);
+            return result
+        }
+        to_explore.push_back(start);
+    }
+
+    while let Some(current) = to_explore.pop_front() {
+        let distance = result.distances[&current] + 1;
+        for next in successors(&current) {
+            // the first time a state is reached is always on a shortest path
+            if result.distances.contains_key(&next) {
+                continue
+            }
+            result.distances.insert(next.clone(), distance);
+            result.parents.insert(next.clone(), current.clone());
+            if is_goal(&next) {
+                result.goal = Some(next);
+                return result
+            }
+            to_explore.push_back(next);
+        }
+    }
+
+    result
+}
+
+impl <N> Bfs<N> where N: Hash + Eq + Clone {
+    /// The goal the search stopped at, None if it visited everything without finding one.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Number of steps from the nearest start, None if the state was not reached.
+    pub fn distance(&self, state: &N) -> Option<usize> {
+        self.distances.get(state).copied()
+    }
+
+    /// The state this one was reached from, None for the starts and states that were not reached.
+    pub fn parent(&self, state: &N) -> Option<&N> {
+        self.parents.get(state)
+    }
+
+    /// The distances of all reached states, including the starts.
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.distances
+    }
+
+    /// A shortest path from one of the starts to the given state (both included), None if it was not reached.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(goal) {
+            return None
+        }
+
+        let mut path = vec![goal.clone()];
+        while let Some(parent) = self.parent(path.last().expect("path is never empty")) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::map::{Map, Position};
+    use super::*;
+
+    fn maze() -> Map<char> {
+        Map::parse_chars("\
+            ..#...\n\
+            .##.##\n\
+            ....#.", Ok).unwrap()
+    }
+
+    fn open_neighbors(map: &Map<char>) -> impl FnMut(&Position) -> Vec<Position> + '_ {
+        |position| map.neighbors(position)
+            .filter(|(_, _, c)| **c == '.')
+            .map(|(neighbor_position, _, _)| neighbor_position)
+            .collect()
+    }
+
+    #[test]
+    fn shortest_path_through_maze() {
+        let map = maze();
+        let result = bfs([(0, 0)], open_neighbors(&map), |_| false);
+
+        assert_eq!(result.goal(), None);
+        assert_eq!(result.distance(&(5, 2)), None);
+        assert_eq!(result.distance(&(3, 0)), Some(7));
+        assert_eq!(result.distances().len(), 11);
+        assert_eq!(result.path_to(&(3, 1)), Some(vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2), (3, 1)]));
+        assert_eq!(result.path_to(&(0, 0)), Some(vec![(0, 0)]));
+    }
+
+    #[test]
+    fn stop_at_goal() {
+        let map = maze();
+        let result = bfs([(0, 0)], open_neighbors(&map), |position| *position == (0, 2));
+
+        assert_eq!(result.goal(), Some(&(0, 2)));
+        assert_eq!(result.distance(&(3, 2)), None);
+        assert_eq!(result.path_to(&(0, 2)).map(|path| path.len()), Some(3));
+    }
+
+    #[test]
+    fn multiple_starts() {
+        let map = maze();
+        let result = bfs([(0, 0), (5, 0)], open_neighbors(&map), |_| false);
+
+        assert_eq!(result.distance(&(3, 2)), Some(4));
+        assert_eq!(result.distance(&(1, 2)), Some(3));
+        assert_eq!(result.path_to(&(3, 1)).unwrap().first(), Some(&(5, 0)));
+        assert_eq!(result.parent(&(5, 0)), None);
+    }
+}
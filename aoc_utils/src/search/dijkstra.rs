@@ -0,0 +1,326 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The result of [dijkstra] or [astar]: the lowest cost of every settled state and all the states it can be
+/// reached from with that cost.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+    goals: Vec<N>,
+}
+
+/// Find the cheapest way from the start to the states `is_goal` accepts. The successors are returned together
+/// with the (non-negative) cost of the step to them. All goals that can be reached with the lowest cost are
+/// found, e.g. every direction a position can be entered from. Without a goal every reachable state is settled.
+/// A state is only recorded as predecessor of states that are settled after it, so steps that cost nothing can
+/// not make the paths go in circles, but then not every order of the free steps is found.
+pub fn dijkstra<N, C, S, I, G>(start: N, successors: S, is_goal: G) -> ShortestPaths<N, C>
+    where N: Hash + Eq + Clone,
+          C: Copy + Ord + Add<Output = C> + Default,
+          S: FnMut(&N) -> I,
+          I: IntoIterator<Item = (N, C)>,
+          G: FnMut(&N) -> bool
+{
+    astar(start, successors, is_goal, |_| C::default())
+}
+
+/// Like [dijkstra] but states that look closer to the goal are explored first. The heuristic must never
+/// overestimate the remaining cost and must not decrease by more than the cost of a step, otherwise the result
+/// is not the cheapest.
+pub fn astar<N, C, S, I, G, H>(start: N, mut successors: S, mut is_goal: G, mut heuristic: H) -> ShortestPaths<N, C>
+    where N: Hash + Eq + Clone,
+          C: Copy + Ord + Add<Output = C> + Default,
+          S: FnMut(&N) -> I,
+          I: IntoIterator<Item = (N, C)>,
+          G: FnMut(&N) -> bool,
+          H: FnMut(&N) -> C
+{
+    let mut result = ShortestPaths {
+        costs: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: vec![],
+    };
+    let mut goal_cost: Option<C> = None;
+    let mut settled = HashSet::new();
+
+    let mut to_explore = BinaryHeap::new();
+    result.costs.insert(start.clone(), C::default());
+    to_explore.push(Queued { priority: heuristic(&start), cost: C::default(), state: start });
+
+    while let Some(Queued { priority, cost, state }) = to_explore.pop() {
+        // after the goal only states that can still be optimal predecessors of it are settled
+        if goal_cost.is_some_and(|goal_cost| priority > goal_cost) {
+            break
+        }
+        // a cheaper way to this state was found after it was queued
+        if cost > result.costs[&state] {
+            continue
+        }
+        settled.insert(state.clone());
+        if is_goal(&state) {
+            // goals are popped in order of their cost, so all goals up to the break above are equally cheap
+            goal_cost = Some(cost);
+            result.goals.push(state);
+            continue
+        }
+
+        for (next, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+            match result.costs.get(&next) {
+                Some(known_cost) if next_cost > *known_cost => {},
+                // a settled state (like the start) can only be reached again with the same cost by free steps
+                Some(_) if settled.contains(&next) => {},
+                Some(known_cost) if next_cost == *known_cost => {
+                    result.predecessors.entry(next).or_default().push(state.clone());
+                },
+                _ => {
+                    result.costs.insert(next.clone(), next_cost);
+                    result.predecessors.insert(next.clone(), vec![state.clone()]);
+                    to_explore.push(Queued { priority: next_cost + heuristic(&next), cost: next_cost, state: next });
+                }
+            }
+        }
+    }
+
+    result
+}
+
+impl <N, C> ShortestPaths<N, C> where N: Hash + Eq + Clone, C: Copy {
+    /// All goals that can be reached with the lowest cost, empty if no goal could be reached.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// The cost of the cheapest way to the goals, None if no goal could be reached.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goals.first().and_then(|goal| self.cost(goal))
+    }
+
+    /// The lowest known cost from the start, None if the state was not reached.
+    pub fn cost(&self, state: &N) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// All states the given one can be reached from with the lowest cost, empty for the start.
+    pub fn predecessors(&self, state: &N) -> &[N] {
+        self.predecessors.get(state).map_or(&[], |predecessors| predecessors.as_slice())
+    }
+
+    /// One of the cheapest paths from the start to the given state (both included), None if it was not reached.
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(goal) {
+            return None
+        }
+
+        let mut path = vec![goal.clone()];
+        while let Some(predecessor) = self.predecessors(path.last().expect("path is never empty")).first() {
+            path.push(predecessor.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Enumerate every cheapest path from the start to any of the given states, e.g. [ShortestPaths::goals]. States
+    /// that were not reached have no paths. The number of paths can grow exponentially, see
+    /// [ShortestPaths::states_on_paths_to] if only the states are needed.
+    pub fn all_paths_to(&self, targets: &[N]) -> Vec<Vec<N>> {
+        let mut paths = vec![];
+        // paths are built backwards from the targets
+        let mut partial_paths: Vec<Vec<N>> = targets.iter()
+            .filter(|target| self.costs.contains_key(target))
+            .map(|target| vec![target.clone()])
+            .collect();
+        while let Some(partial_path) = partial_paths.pop() {
+            let predecessors = self.predecessors(partial_path.last().expect("path is never empty"));
+            if predecessors.is_empty() {
+                let mut path = partial_path;
+                path.reverse();
+                paths.push(path);
+                continue
+            }
+            for predecessor in predecessors {
+                let mut longer_path = partial_path.clone();
+                longer_path.push(predecessor.clone());
+                partial_paths.push(longer_path);
+            }
+        }
+
+        paths
+    }
+
+    /// All states that lie on any of the cheapest paths to any of the given states, including start and targets.
+    pub fn states_on_paths_to(&self, targets: &[N]) -> HashSet<N> {
+        let mut states = HashSet::new();
+        let mut to_visit: Vec<N> = targets.iter()
+            .filter(|target| self.costs.contains_key(target))
+            .cloned()
+            .collect();
+        while let Some(state) = to_visit.pop() {
+            for predecessor in self.predecessors(&state) {
+                if !states.contains(predecessor) {
+                    to_visit.push(predecessor.clone());
+                }
+            }
+            states.insert(state);
+        }
+
+        states
+    }
+}
+
+/// An entry of the priority queue, the one with the lowest priority is the greatest so it is popped first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    state: N,
+}
+
+impl <N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl <N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl <N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl <N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::map::{Direction, Map, Point, Position};
+    use super::*;
+
+    type Reindeer = (Position, Direction);
+
+    /// Moving forward costs 1, turning by 90 degrees costs 1000 like in a reindeer maze.
+    fn maze() -> Map<char> {
+        Map::parse_chars("\
+            #######\n\
+            #....E#\n\
+            #.#.#.#\n\
+            #S....#\n\
+            #######", Ok).unwrap()
+    }
+
+    fn moves(map: &Map<char>) -> impl FnMut(&Reindeer) -> Vec<(Reindeer, u32)> + '_ {
+        |(position, direction)| {
+            let mut moves = vec![
+                ((*position, direction.turn_clockwise()), 1000),
+                ((*position, direction.turn_counter_clockwise()), 1000),
+            ];
+            if let Some(next) = map.new_position(position, direction).filter(|next| map.get(next) != Some(&'#')) {
+                moves.push(((next, *direction), 1));
+            }
+            moves
+        }
+    }
+
+    #[test]
+    fn cheapest_way_through_maze() {
+        let map = maze();
+        let start = (map.position_of(&'S').unwrap(), Direction::Right);
+        let end = map.position_of(&'E').unwrap();
+
+        let result = dijkstra(start, moves(&map), |(position, _)| *position == end);
+
+        assert_eq!(result.goals(), &[(end, Direction::Up)]);
+        assert_eq!(result.goal_cost(), Some(1006));
+        assert_eq!(result.path_to(&result.goals()[0]).unwrap().first(), Some(&start));
+
+        // going up earlier needs a second turn
+        assert_eq!(result.all_paths_to(result.goals()).len(), 1);
+        let tiles: HashSet<Position> = result.states_on_paths_to(result.goals()).into_iter().map(|(position, _)| position).collect();
+        assert_eq!(tiles.len(), 7);
+    }
+
+    #[test]
+    fn all_paths_through_open_room() {
+        let map = Map::with_size(3, 3, '.');
+        let steps = |position: &Position| map.neighbors(position)
+            .map(|(neighbor_position, _, _)| (neighbor_position, 1))
+            .collect::<Vec<_>>();
+
+        let result = dijkstra((0, 0), steps, |position| *position == (2, 2));
+
+        assert_eq!(result.goal_cost(), Some(4));
+        let predecessors: HashSet<_> = result.predecessors(&(2, 2)).iter().copied().collect();
+        assert_eq!(predecessors, HashSet::from([(2, 1), (1, 2)]));
+        let paths = result.all_paths_to(&[(2, 2)]);
+        assert_eq!(paths.len(), 6);
+        assert!(paths.iter().all(|path| path.len() == 5 && path.first() == Some(&(0, 0))));
+        assert_eq!(result.states_on_paths_to(&[(2, 2)]).len(), 9);
+    }
+
+    #[test]
+    fn all_equally_cheap_goals() {
+        let map = Map::with_size(3, 3, '.');
+        // the state is the position and the direction of the last step
+        let steps = |(position, _): &Reindeer| map.neighbors(position)
+            .map(|(neighbor_position, direction, _)| ((neighbor_position, direction), 1))
+            .collect::<Vec<_>>();
+
+        let result = dijkstra(((0, 0), Direction::Right), steps, |(position, _)| *position == (2, 2));
+
+        let goals: HashSet<_> = result.goals().iter().copied().collect();
+        assert_eq!(goals, HashSet::from([((2, 2), Direction::Right), ((2, 2), Direction::Down)]));
+        assert_eq!(result.goal_cost(), Some(4));
+        assert_eq!(result.all_paths_to(result.goals()).len(), 6);
+        assert_eq!(result.states_on_paths_to(result.goals()).len(), 13);
+    }
+
+    #[test]
+    fn astar_agrees_with_dijkstra() {
+        let map = maze();
+        let start = (map.position_of(&'S').unwrap(), Direction::Right);
+        let end = map.position_of(&'E').unwrap();
+        let distance = |(position, _): &Reindeer| Point::from(*position).manhattan_distance(&Point::from(end)) as u32;
+
+        let result = astar(start, moves(&map), |(position, _)| *position == end, distance);
+
+        assert_eq!(result.goal_cost(), Some(1006));
+        assert_eq!(result.all_paths_to(result.goals()).len(), 1);
+    }
+
+    #[test]
+    fn free_steps_do_not_go_in_circles() {
+        // 0 and 1 can be reached from each other for free, 2 is one step away from both
+        let steps = |n: &u32| match n {
+            0 => vec![(1, 0), (2, 1)],
+            1 => vec![(0, 0), (2, 1)],
+            _ => vec![],
+        };
+
+        let result = dijkstra(0u32, steps, |n| *n == 2);
+
+        assert_eq!(result.goal_cost(), Some(1));
+        assert_eq!(result.predecessors(&0), &[] as &[u32]);
+        assert_eq!(result.predecessors(&1), &[0]);
+        assert_eq!(result.path_to(&2), Some(vec![0, 2]));
+        assert_eq!(result.all_paths_to(&[2]).len(), 2);
+        assert_eq!(result.states_on_paths_to(&[2]), HashSet::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let result = dijkstra(0u32, |n| if *n < 5 { vec![(n + 1, 1u32)] } else { vec![] }, |n| *n == 10);
+
+        assert!(result.goals().is_empty());
+        assert_eq!(result.goal_cost(), None);
+        assert_eq!(result.cost(&5), Some(5));
+        assert_eq!(result.predecessors(&0), &[] as &[u32]);
+        assert!(result.all_paths_to(&[10]).is_empty());
+    }
+}
@@ -0,0 +1,11 @@
+//! Generic graph searches. The graph is never built up front, it is described by a function that returns the
+//! successors of a state, so a state can be a map [Position](crate::map::Position) as well as anything else that
+//! can be hashed, e.g. a position together with a direction.
+
+mod bfs;
+mod dijkstra;
+mod paths;
+
+pub use bfs::{bfs, Bfs};
+pub use dijkstra::{astar, dijkstra, ShortestPaths};
+pub use paths::{count_paths, CycleError, PathCounts};
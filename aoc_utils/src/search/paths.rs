@@ -0,0 +1,210 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+
+/// The result of [count_paths]: the number of distinct paths for every source and sink that are connected.
+#[derive(Debug, Clone)]
+pub struct PathCounts<N> {
+    counts: HashMap<(N, N), usize>,
+}
+
+/// The error of [count_paths] if the successors lead back to a state, there would be endless paths through it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N> {
+    /// A state on the cycle
+    pub state: N,
+}
+
+impl <N: Debug> Display for CycleError<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "can only count paths in a graph without cycles, but {:?} can be reached from itself", self.state)
+    }
+}
+
+impl <N: Debug> std::error::Error for CycleError<N> {}
+
+/// Count the distinct paths from each of the sources to each state `is_sink` accepts. A path ends at the
+/// first sink it reaches. The successors must describe a graph without cycles (e.g. every step goes up),
+/// otherwise there would be endless paths and a [CycleError] is returned. Every state is only expanded once, so the number of
+/// paths can be far larger than what could be enumerated.
+pub fn count_paths<N, S, I, P>(sources: impl IntoIterator<Item = N>, mut successors: S, mut is_sink: P) -> Result<PathCounts<N>, CycleError<N>>
+    where N: Hash + Eq + Clone, S: FnMut(&N) -> I, I: IntoIterator<Item = N>, P: FnMut(&N) -> bool
+{
+    let mut graph = Graph::default();
+
+    let mut counts = HashMap::new();
+    for source in sources {
+        let source_id = graph.id(source);
+        graph.count_from(source_id, &mut successors, &mut is_sink)?;
+        for (sink_id, count) in &graph.counts[source_id] {
+            let key = (graph.states[source_id].clone(), graph.states[*sink_id].clone());
+            *counts.entry(key).or_default() += count;
+        }
+    }
+
+    Ok(PathCounts { counts })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    InProgress,
+    Done,
+}
+
+/// The states seen so far, each with an id that indexes the other vectors.
+struct Graph<N> {
+    ids: HashMap<N, usize>,
+    states: Vec<N>,
+    visits: Vec<Visit>,
+    /// Number of paths from the state to every sink (by id) it is connected to, complete once the state is done.
+    counts: Vec<HashMap<usize, usize>>,
+}
+
+impl <N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph { ids: HashMap::new(), states: vec![], visits: vec![], counts: vec![] }
+    }
+}
+
+/// A state whose successors are being counted, `next` is the index of the successor to look at next.
+struct Frame {
+    id: usize,
+    successors: Vec<usize>,
+    next: usize,
+}
+
+impl <N> Graph<N> where N: Hash + Eq + Clone {
+    fn id(&mut self, state: N) -> usize {
+        if let Some(id) = self.ids.get(&state) {
+            return *id
+        }
+        let id = self.states.len();
+        self.ids.insert(state.clone(), id);
+        self.states.push(state);
+        self.visits.push(Visit::New);
+        self.counts.push(HashMap::new());
+        id
+    }
+
+    /// Start a visit of a new state, sinks are done right away. Returns the frame if the successors need to be
+    /// counted.
+    fn start<S, I, P>(&mut self, id: usize, successors: &mut S, is_sink: &mut P) -> Option<Frame>
+        where S: FnMut(&N) -> I, I: IntoIterator<Item = N>, P: FnMut(&N) -> bool
+    {
+        if is_sink(&self.states[id]) {
+            self.counts[id].insert(id, 1);
+            self.visits[id] = Visit::Done;
+            return None
+        }
+
+        self.visits[id] = Visit::InProgress;
+        let successors: Vec<N> = successors(&self.states[id]).into_iter().collect();
+        let successors = successors.into_iter().map(|state| self.id(state)).collect();
+        Some(Frame { id, successors, next: 0 })
+    }
+
+    /// Add the path counts of a done state to the ones of its predecessor.
+    fn add_counts(&mut self, from: usize, to: usize) {
+        let from_counts = std::mem::take(&mut self.counts[from]);
+        for (sink, count) in &from_counts {
+            *self.counts[to].entry(*sink).or_default() += count;
+        }
+        self.counts[from] = from_counts;
+    }
+
+    /// Count the paths from the state with a post-order depth first search that does not recurse, so long paths
+    /// can not overflow the stack.
+    fn count_from<S, I, P>(&mut self, id: usize, successors: &mut S, is_sink: &mut P) -> Result<(), CycleError<N>>
+        where S: FnMut(&N) -> I, I: IntoIterator<Item = N>, P: FnMut(&N) -> bool
+    {
+        if self.visits[id] == Visit::Done {
+            return Ok(())
+        }
+
+        let mut stack: Vec<Frame> = self.start(id, successors, is_sink).into_iter().collect();
+        while let Some(frame) = stack.last_mut() {
+            let Some(&next) = frame.successors.get(frame.next) else {
+                // all successors are counted
+                let done = stack.pop().expect("stack is not empty").id;
+                self.visits[done] = Visit::Done;
+                if let Some(predecessor) = stack.last() {
+                    self.add_counts(done, predecessor.id);
+                }
+                continue
+            };
+            frame.next += 1;
+            let current = frame.id;
+
+            match self.visits[next] {
+                Visit::Done => self.add_counts(next, current),
+                Visit::InProgress => return Err(CycleError { state: self.states[next].clone() }),
+                Visit::New => match self.start(next, successors, is_sink) {
+                    Some(next_frame) => stack.push(next_frame),
+                    None => self.add_counts(next, current),
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl <N> PathCounts<N> where N: Hash + Eq + Clone {
+    /// Number of distinct paths from the source to the sink, 0 if they are not connected.
+    pub fn count(&self, source: &N, sink: &N) -> usize {
+        self.counts.get(&(source.clone(), sink.clone())).copied().unwrap_or(0)
+    }
+
+    /// Iterate all sources and sinks that are connected by at least one path, with the number of paths.
+    pub fn connected(&self) -> impl Iterator<Item = (&N, &N, usize)> {
+        self.counts.iter().map(|((source, sink), count)| (source, sink, *count))
+    }
+
+    /// Number of distinct paths from any source to any sink.
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1 -> 2 -> 4, 1 -> 3 -> 4, 2 -> 3, 4 -> 5 and 4 -> 6
+    fn successors(state: &u32) -> Vec<u32> {
+        match state {
+            1 => vec![2, 3],
+            2 => vec![3, 4],
+            3 => vec![4],
+            4 => vec![5, 6],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn count_between_sets() {
+        let counts = count_paths([1, 2], successors, |state| *state >= 5).unwrap();
+
+        assert_eq!(counts.count(&1, &5), 3);
+        assert_eq!(counts.count(&2, &6), 2);
+        assert_eq!(counts.count(&5, &6), 0);
+        assert_eq!(counts.connected().count(), 4);
+        assert_eq!(counts.total(), 10);
+    }
+
+    #[test]
+    fn paths_end_at_first_sink() {
+        let counts = count_paths([1], successors, |state| *state == 4 || *state == 6).unwrap();
+
+        assert_eq!(counts.count(&1, &4), 3);
+        assert_eq!(counts.count(&1, &6), 0);
+    }
+
+    #[test]
+    fn refuse_cycles() {
+        let result = count_paths([0], |state: &u32| vec![(state + 1) % 3], |_| false);
+
+        assert_eq!(result.unwrap_err(), CycleError { state: 0 });
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::str::FromStr;
+use anyhow::{Result, Context, bail};
+
+/// One of the two parts every puzzle consists of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl FromStr for Part {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("Part must be 1 or 2, got '{s}'")
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// The common interface of every day. The input is parsed once and both parts are computed from it,
+/// so tooling around the days (runner, benchmarks, tests) only has to be written once.
+pub trait Solution {
+    /// The parsed puzzle input.
+    type Input;
+
+    /// The answer of both parts, it only needs to be printable so it can be submitted.
+    type Answer: Display;
+
+    /// Parse the raw puzzle input (the content of the input file, not its name).
+    fn parse(raw_data: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Answer>;
+
+    /// Parse the raw puzzle input and solve one part with it.
+    fn solve(raw_data: &str, part: Part) -> Result<Self::Answer> {
+        let input = Self::parse(raw_data)?;
+        match part {
+            Part::One => Self::part1(&input),
+            Part::Two => Self::part2(&input),
+        }
+    }
+
+    /// Read the given input file and solve one part with it.
+    fn solve_file(filename: &str, part: Part) -> Result<Self::Answer> {
+        Self::solve(&read_input(filename)?, part)
+    }
+}
+
+/// Read the raw puzzle input from a file.
+pub fn read_input(filename: &str) -> Result<String> {
+    read_to_string(filename).with_context(|| format!("Could not read input file {filename}"))
+}
+
+/// Generates the tests of a day from the examples listed in the `examples.toml` next to the source file the
+/// macro is used in. Every example gets a module with one test per part that has an expected answer, e.g.
+///
+/// ```toml
+/// [[example]]
+/// file = "test_input.txt"
+/// part_1 = 14
+/// part_2 = 34
+///
+/// [[example]]
+/// file = "test_input_part_2_example.txt"
+/// part_2 = 9
+/// ```
+///
+/// generates `test_input::part_1`, `test_input::part_2` and `test_input_part_2_example::part_2` with
+/// `aoc_tests!(Day08)`, so the manifest stays the only place the expected answers are kept.
+///
+/// The macro does not read the manifest itself. It includes `$OUT_DIR/examples/<source file>`, so it only
+/// works in a crate whose build script writes that file for every source file the macro is used in, with one
+/// `aoc_tests!(@test "file", part_1, One, "14")` per test (see the `build.rs` of the solutions crate).
+#[macro_export]
+macro_rules! aoc_tests {
+    ($solution:ty) => {
+        #[allow(dead_code)]
+        type ExampleSolution = $solution;
+        #[allow(dead_code)]
+        const EXAMPLES_SOURCE: &str = file!();
+
+        include!(concat!(env!("OUT_DIR"), "/examples/", file!()));
+    };
+    (@test $file:literal, $test:ident, $part:ident, $expected:literal) => {
+        #[test]
+        fn $test() {
+            let filename = ::std::path::Path::new(super::EXAMPLES_SOURCE).with_file_name($file);
+            let filename = filename.to_str().expect("example file names are valid UTF-8");
+            let result = <super::ExampleSolution as $crate::solution::Solution>::solve_file(filename, $crate::solution::Part::$part)
+                .unwrap();
+            assert_eq!(result.to_string(), $expected);
+        }
+    };
+}
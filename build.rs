@@ -0,0 +1,62 @@
+//! Generates the example tests of every day from its `examples.toml`, see `aoc_utils::aoc_tests!`. Each day
+//! folder `src/day_NN` gets a file `$OUT_DIR/examples/src/day_NN/mod.rs`, which is empty if the day has no
+//! manifest, with one module per example and one test per part that has an expected answer.
+
+use std::env;
+use std::fmt::Write;
+use std::fs::{create_dir_all, read_dir, read_to_string, write};
+use std::path::Path;
+
+#[path = "src/manifest.rs"]
+mod manifest;
+
+use manifest::{Manifest, MANIFEST_FILE};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src");
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    for entry in read_dir("src").expect("src can be listed") {
+        let day_directory = entry.expect("src can be listed").path();
+        let is_day = day_directory.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("day_"));
+        if !is_day || !day_directory.is_dir() {
+            continue
+        }
+
+        let target = Path::new(&out_dir).join("examples").join(&day_directory).join("mod.rs");
+        create_dir_all(target.parent().expect("target is in a folder")).expect("out dir is writable");
+        write(&target, example_tests(&day_directory)).expect("out dir is writable");
+    }
+}
+
+fn example_tests(day_directory: &Path) -> String {
+    let manifest_path = day_directory.join(MANIFEST_FILE);
+    if !manifest_path.exists() {
+        return String::new()
+    }
+
+    let raw_manifest = read_to_string(&manifest_path).expect("manifest can be read");
+    let manifest: Manifest = toml::from_str(&raw_manifest)
+        .unwrap_or_else(|error| panic!("{} is invalid: {error}", manifest_path.display()));
+
+    let mut tests = String::new();
+    for example in &manifest.examples {
+        let module: String = example.file.trim_end_matches(".txt")
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        writeln!(tests, "mod {module} {{").unwrap();
+        for (expected, test, part) in [(&example.part_1, "part_1", "One"), (&example.part_2, "part_2", "Two")] {
+            if let Some(expected) = expected {
+                writeln!(tests, "    aoc_utils::aoc_tests!(@test {:?}, {test}, {part}, {:?});", example.file, expected.to_string())
+                    .unwrap();
+            }
+        }
+        writeln!(tests, "}}").unwrap();
+    }
+
+    tests
+}
@@ -0,0 +1,104 @@
+//! Allocation counting, only available with the `count-allocations` feature since it replaces the global
+//! allocator. Only allocations made on the measuring thread are counted, so work rayon runs on its thread
+//! pool (e.g. day 6 part 2) is not included.
+
+use anyhow::{Result, Context};
+use aoc_utils::solution::Part;
+use crate::runner::Day;
+
+/// Number of allocations and the total number of bytes allocated by one step.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+}
+
+/// The allocations of parsing and both parts of a single day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayAllocations {
+    pub day: u8,
+    pub parse: Allocations,
+    pub part_1: Allocations,
+    pub part_2: Allocations,
+}
+
+/// Run the closure and count the allocations it makes.
+pub fn measure<R>(run: impl FnOnce() -> R) -> (R, Allocations) {
+    let mut result = None;
+    let info = allocation_counter::measure(|| {
+        result = Some(run());
+    });
+
+    let allocations = Allocations {
+        count: info.count_total,
+        bytes: info.bytes_total,
+    };
+    (result.expect("closure has been run"), allocations)
+}
+
+/// Run the closure and panic if it allocates more than `max_count` times. Returns the result of the closure,
+/// so it can be wrapped around any call in a test.
+#[track_caller]
+pub fn assert_max_allocations<R>(max_count: u64, run: impl FnOnce() -> R) -> R {
+    let (result, allocations) = measure(run);
+    assert!(
+        allocations.count <= max_count,
+        "expected at most {max_count} allocations, but got {} ({} bytes)", allocations.count, allocations.bytes
+    );
+    result
+}
+
+/// Run the closure and panic if it allocates at all.
+#[track_caller]
+pub fn assert_no_allocations<R>(run: impl FnOnce() -> R) -> R {
+    assert_max_allocations(0, run)
+}
+
+/// Count the allocations of parsing and both parts of a day. The parts include rendering the answer
+/// to a string, which is a single small allocation.
+pub fn measure_day(day: &Day, raw_data: &str) -> Result<DayAllocations> {
+    let (parsed, parse) = measure(|| day.solution.parse(raw_data));
+    let parsed = parsed.with_context(|| format!("Day {:02} failed to parse its input", day.number))?;
+
+    let mut parts = [Allocations::default(); 2];
+    for (part, allocations) in Part::BOTH.iter().zip(parts.iter_mut()) {
+        let (result, part_allocations) = measure(|| day.solution.solve(parsed.as_ref(), *part));
+        result.with_context(|| format!("Day {:02} part {part} failed", day.number))?;
+        *allocations = part_allocations;
+    }
+
+    let [part_1, part_2] = parts;
+    Ok(DayAllocations { day: day.number, parse, part_1, part_2 })
+}
+
+/// Log the allocations of a day in a human readable way.
+pub fn log_allocations(allocations: &DayAllocations) {
+    let steps = [("parse ", &allocations.parse), ("part 1", &allocations.part_1), ("part 2", &allocations.part_2)];
+    for (name, step) in steps {
+        info!("Day {:02} {name}: {:>10} allocations {:>12} bytes", allocations.day, step.count, step.bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_max_allocations, assert_no_allocations, measure, Allocations};
+
+    #[test]
+    fn count_allocations() {
+        let (result, allocations) = measure(|| "hello, world".to_string());
+        assert_eq!(result, "hello, world");
+        assert_eq!(allocations, Allocations { count: 1, bytes: 12 });
+    }
+
+    #[test]
+    fn assert_allocation_limits() {
+        assert_eq!(assert_no_allocations(|| 1 + 1), 2);
+        assert_max_allocations(1, || vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected at most 0 allocations")]
+    fn fail_on_too_many_allocations() {
+        assert_no_allocations(|| vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::path::Path;
+use std::str::FromStr;
+use anyhow::{Result, Context, bail};
+use serde::{Deserialize, Serialize};
+use aoc_utils::solution::Part;
+
+/// The file the answers are stored in, relative to the repository root.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The feedback Advent of Code gives for a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint in which direction
+    Wrong,
+}
+
+impl FromStr for Feedback {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "correct" => Ok(Feedback::Correct),
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            _ => bail!("Feedback must be one of correct, too-high, too-low or wrong, got '{s}'")
+        }
+    }
+}
+
+impl Display for Feedback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feedback::Correct => f.write_str("correct"),
+            Feedback::TooHigh => f.write_str("too high"),
+            Feedback::TooLow => f.write_str("too low"),
+            Feedback::Wrong => f.write_str("wrong"),
+        }
+    }
+}
+
+/// Everything we know about the answer of a single part.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartAnswers {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_high: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub too_low: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+    /// The earliest time the next answer may be submitted, in seconds since the unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_at: Option<u64>,
+}
+
+/// The result of checking a computed answer against the known answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// Matches the known correct answer
+    Correct,
+    /// A correct answer is known and the computed one differs from it
+    Mismatch { expected: String },
+    /// The answer has already been rejected with the given feedback
+    KnownWrong(Feedback),
+    /// The answer was never submitted, but an earlier "too high" or "too low" feedback already rules it out
+    RuledOut(Feedback),
+    /// Nothing is known that could confirm or reject the answer
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer is known (or can be deduced) to be wrong.
+    pub fn is_wrong(&self) -> bool {
+        !matches!(self, Verdict::Correct | Verdict::Unknown)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => f.write_str("correct"),
+            Verdict::Mismatch { expected } => write!(f, "MISMATCH, expected {expected}"),
+            Verdict::KnownWrong(feedback) => write!(f, "already submitted, was {feedback}"),
+            Verdict::RuledOut(feedback) => write!(f, "{feedback} according to earlier submissions"),
+            Verdict::Unknown => f.write_str("unknown"),
+        }
+    }
+}
+
+impl PartAnswers {
+    /// Check a computed answer against what is known about this part.
+    pub fn check(&self, answer: &str) -> Verdict {
+        if let Some(correct) = &self.correct {
+            return if correct == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Mismatch { expected: correct.clone() }
+            }
+        }
+
+        let contains = |answers: &Vec<String>| answers.iter().any(|known| known == answer);
+        if contains(&self.too_high) {
+            return Verdict::KnownWrong(Feedback::TooHigh)
+        }
+        if contains(&self.too_low) {
+            return Verdict::KnownWrong(Feedback::TooLow)
+        }
+        if contains(&self.wrong) {
+            return Verdict::KnownWrong(Feedback::Wrong)
+        }
+
+        // numeric answers can also be ruled out if they are above a known too high or below a known too low answer
+        if let Ok(answer) = answer.parse::<i128>() {
+            let numbers = |answers: &Vec<String>| answers.iter()
+                .filter_map(|known| known.parse::<i128>().ok())
+                .collect::<Vec<_>>();
+            if numbers(&self.too_high).into_iter().any(|too_high| answer >= too_high) {
+                return Verdict::RuledOut(Feedback::TooHigh)
+            }
+            if numbers(&self.too_low).into_iter().any(|too_low| answer <= too_low) {
+                return Verdict::RuledOut(Feedback::TooLow)
+            }
+        }
+
+        Verdict::Unknown
+    }
+
+    /// Remember the feedback that was given for an answer.
+    pub fn record(&mut self, answer: &str, feedback: Feedback) {
+        let answer = answer.to_string();
+        let list = match feedback {
+            Feedback::Correct => {
+                self.correct = Some(answer);
+                return
+            }
+            Feedback::TooHigh => &mut self.too_high,
+            Feedback::TooLow => &mut self.too_low,
+            Feedback::Wrong => &mut self.wrong,
+        };
+        if !list.contains(&answer) {
+            list.push(answer);
+        }
+    }
+}
+
+/// The answers of all days, stored as `[day_05.part_2]` tables in [ANSWERS_FILE].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, BTreeMap<String, PartAnswers>>,
+}
+
+impl Answers {
+    /// Load the answers from the given file. A missing file is treated as no answers being known yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Answers::default())
+        }
+        let raw_data = read_to_string(path).with_context(|| format!("Could not read {}", path.display()))?;
+        toml::from_str(&raw_data).with_context(|| format!("Could not parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let raw_data = toml::to_string_pretty(self)?;
+        write(path, raw_data).with_context(|| format!("Could not write {}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&PartAnswers> {
+        self.days.get(&day_key(day))
+            .and_then(|parts| parts.get(&part_key(part)))
+    }
+
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Verdict {
+        self.get(day, part)
+            .map_or(Verdict::Unknown, |answers| answers.check(answer))
+    }
+
+    /// The answers of a part for modification, they are created if nothing is known about the part yet.
+    pub fn get_mut(&mut self, day: u8, part: Part) -> &mut PartAnswers {
+        self.days.entry(day_key(day))
+            .or_default()
+            .entry(part_key(part))
+            .or_default()
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, feedback: Feedback) {
+        self.get_mut(day, part).record(answer, feedback)
+    }
+}
+
+fn day_key(day: u8) -> String {
+    format!("day_{day:02}")
+}
+
+fn part_key(part: Part) -> String {
+    format!("part_{part}")
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::solution::Part;
+    use super::{Answers, Feedback, Verdict};
+
+    #[test]
+    fn check_against_correct_answer() {
+        let mut answers = Answers::default();
+        answers.record(5, Part::One, "143", Feedback::Correct);
+
+        assert_eq!(answers.check(5, Part::One, "143"), Verdict::Correct);
+        assert_eq!(answers.check(5, Part::One, "144"), Verdict::Mismatch { expected: "143".to_string() });
+        assert_eq!(answers.check(5, Part::Two, "144"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn flag_known_and_ruled_out_answers() {
+        let mut answers = Answers::default();
+        answers.record(5, Part::Two, "9985", Feedback::TooHigh);
+        answers.record(5, Part::Two, "100", Feedback::TooLow);
+
+        assert_eq!(answers.check(5, Part::Two, "9985"), Verdict::KnownWrong(Feedback::TooHigh));
+        assert_eq!(answers.check(5, Part::Two, "10000"), Verdict::RuledOut(Feedback::TooHigh));
+        assert_eq!(answers.check(5, Part::Two, "99"), Verdict::RuledOut(Feedback::TooLow));
+        assert_eq!(answers.check(5, Part::Two, "5000"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn round_trip_toml() {
+        let mut answers = Answers::default();
+        answers.record(5, Part::Two, "9985", Feedback::TooHigh);
+        answers.record(1, Part::One, "11", Feedback::Correct);
+
+        let raw_data = toml::to_string_pretty(&answers).unwrap();
+        assert!(raw_data.contains("[day_05.part_2]"));
+        assert_eq!(toml::from_str::<Answers>(&raw_data).unwrap(), answers);
+    }
+}
@@ -0,0 +1,127 @@
+use std::time::{Duration, Instant};
+use anyhow::{Result, Context};
+use rayon::ThreadPoolBuilder;
+use serde::Serialize;
+use aoc_utils::solution::Part;
+use crate::runner::{Day, InputSource};
+
+/// Summary of the timings of one step over all iterations, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let total: Duration = samples.iter().sum();
+        Stats {
+            min_ns: samples[0].as_nanos(),
+            median_ns: samples[samples.len() / 2].as_nanos(),
+            mean_ns: total.as_nanos() / samples.len() as u128,
+        }
+    }
+}
+
+/// The timings of parsing and both parts of a single day.
+#[derive(Debug, Clone, Serialize)]
+pub struct DayBenchmark {
+    pub day: u8,
+    pub iterations: usize,
+    /// Number of threads rayon was allowed to use
+    pub threads: usize,
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+/// Time parsing, part 1 and part 2 of a day separately over the given number of iterations.
+/// All steps are run on the current rayon thread pool.
+pub fn bench_day(day: &Day, raw_data: &str, iterations: usize) -> Result<DayBenchmark> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part_samples = [Vec::with_capacity(iterations), Vec::with_capacity(iterations)];
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = day.solution.parse(raw_data)
+            .with_context(|| format!("Day {:02} failed to parse its input", day.number))?;
+        parse_samples.push(start.elapsed());
+
+        for (part, samples) in Part::BOTH.iter().zip(part_samples.iter_mut()) {
+            let start = Instant::now();
+            day.solution.solve(parsed.as_ref(), *part)
+                .with_context(|| format!("Day {:02} part {part} failed", day.number))?;
+            samples.push(start.elapsed());
+        }
+    }
+
+    let [part_1_samples, part_2_samples] = part_samples;
+    Ok(DayBenchmark {
+        day: day.number,
+        iterations,
+        threads: rayon::current_num_threads(),
+        parse: Stats::from_samples(parse_samples),
+        part_1: Stats::from_samples(part_1_samples),
+        part_2: Stats::from_samples(part_2_samples),
+    })
+}
+
+/// Benchmark all given days. With the default input files, days without an input are skipped. If a number of
+/// threads is given, the days run on a dedicated rayon thread pool of that size (e.g. 1 to compare parallel
+/// solutions with their sequential execution).
+pub fn bench(days: &[&Day], input: &InputSource, iterations: usize, threads: Option<usize>) -> Result<Vec<DayBenchmark>> {
+    let pool = threads
+        .map(|threads| ThreadPoolBuilder::new().num_threads(threads).build())
+        .transpose()?;
+
+    let mut benchmarks = Vec::with_capacity(days.len());
+    for day in days {
+        if *input == InputSource::DefaultFile && !day.has_default_input() {
+            warn!("Day {:02} skipped, {} does not exist", day.number, day.default_input());
+            continue
+        }
+
+        let raw_data = input.read(day)?;
+        let benchmark = match &pool {
+            Some(pool) => pool.install(|| bench_day(day, &raw_data, iterations))?,
+            None => bench_day(day, &raw_data, iterations)?,
+        };
+        benchmarks.push(benchmark);
+    }
+
+    Ok(benchmarks)
+}
+
+/// Log the timings of a benchmark in a human readable way.
+pub fn log_benchmark(benchmark: &DayBenchmark) {
+    let steps = [("parse ", &benchmark.parse), ("part 1", &benchmark.part_1), ("part 2", &benchmark.part_2)];
+    for (name, stats) in steps {
+        info!(
+            "Day {:02} {name}: min {:>12?} median {:>12?} mean {:>12?} ({} iterations, {} threads)",
+            benchmark.day,
+            Duration::from_nanos(stats.min_ns as u64),
+            Duration::from_nanos(stats.median_ns as u64),
+            Duration::from_nanos(stats.mean_ns as u64),
+            benchmark.iterations,
+            benchmark.threads,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::Stats;
+
+    #[test]
+    fn compute_stats() {
+        let samples = [5, 1, 3, 100].into_iter().map(Duration::from_nanos).collect();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats, Stats { min_ns: 1, median_ns: 5, mean_ns: 27 });
+    }
+}
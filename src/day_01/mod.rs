@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use anyhow::{Result};
+use aoc_utils::solution::Solution;
+
+#[derive(Debug)]
+pub struct Input {
+    col0: Vec<u32>,
+    col1: Vec<u32>
+}
+
+mod parse {
+    use aoc_parse::{parser, prelude::*};
+    use anyhow::{Result, Context};
+    use super::Input;
+
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
+        let parser = parser!(lines(u32 "   " u32));
+
+        let raw_parsed: Vec<(u32, u32)> = parser.parse(raw_data).context("parse error")?;
+
+        let mut col0: Vec<u32> = Vec::with_capacity(raw_parsed.len());
+        let mut col1: Vec<u32> = Vec::with_capacity(raw_parsed.len());
+
+        for (i0, i1) in raw_parsed {
+            col0.push(i0);
+            col1.push(i1);
+        }
+
+        Ok(Input {
+            col0,
+            col1,
+        })
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(input: &Input) -> Result<u32> {
+        let mut col0 = input.col0.clone();
+        let mut col1 = input.col1.clone();
+
+        col0.sort();
+        col1.sort();
+
+        let mut total_distance: u32 = 0;
+        let sorted_pairs = col0.iter().zip(col1);
+        for (i0, i1) in sorted_pairs {
+            total_distance += i0.abs_diff(i1);
+        }
+
+        Ok(total_distance)
+    }
+
+    fn part2(input: &Input) -> Result<u32> {
+        let frequencies_right_list: BTreeMap<&u32, u32> = input.col1
+            .iter().fold(BTreeMap::new(), |mut freq, item| {
+                freq.entry(item)
+                    .and_modify(|count| *count += 1)
+                    .or_insert(1);
+                freq
+            });
+
+        debug!("frequencies_right_list: {:?}", frequencies_right_list);
+
+        let mut similarity_score: u32 = 0;
+        for i0 in &input.col0 {
+            let occurrences_right = frequencies_right_list.get(i0).unwrap_or(&(0u32));
+            similarity_score += i0 * occurrences_right;
+        }
+
+        Ok(similarity_score)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day01;
+
+    aoc_utils::aoc_tests!(Day01);
+}
\ No newline at end of file
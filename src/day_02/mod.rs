@@ -0,0 +1,154 @@
+use anyhow::{Result};
+use itertools::Itertools;
+use aoc_utils::solution::Solution;
+
+#[derive(Debug)]
+pub struct Input {
+    reports: Vec<Vec<i32>>
+}
+
+mod parse {
+    use aoc_parse::{parser, prelude::*};
+    use anyhow::{Result, Context};
+    use super::Input;
+
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
+        let parser = parser!(lines(repeat_sep(i32, " ")));
+
+        let raw_parsed = parser.parse(raw_data).context("parse error")?;
+
+        Ok(Input {
+            reports: raw_parsed
+        })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(input: &Input) -> Result<u32> {
+        debug!("Input: {:?}", input);
+
+        let mut safe_reports = 0;
+        for report in &input.reports {
+
+            let is_safe = is_safe(report, None);
+            debug!("{:?} safe={}", report, is_safe);
+            if is_safe {
+                safe_reports += 1
+            }
+        }
+
+        Ok(safe_reports)
+    }
+
+    fn part2(input: &Input) -> Result<u32> {
+        debug!("Input: {:?}", input);
+
+        let mut safe_reports = 0;
+        for report in &input.reports {
+            if is_safe_with_one_removed(report) {
+                safe_reports += 1
+            }
+        }
+
+        Ok(safe_reports)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ExpectLevelChanges {
+    Increasing,
+    Decreasing,
+}
+
+/// Check if a report is safe. If ignore_index is given that one index is ignored when checking.
+///
+/// The method is a bit long compared to a naive implementation because it avoids any allocations.
+fn is_safe(report: &[i32], ignore_index: Option<usize>) -> bool {
+
+    let mut item_pairs = report.iter().enumerate()
+        // if we want to ignore an index we skip it, if we don't want to ignore anything we let everything trough
+        .filter(|(index, _)| ignore_index != Some(*index))
+        // discard index and deref item
+        .map(|(_, item)| *item)
+        // create window of 2-tuples
+        .tuple_windows::<(_,_)>()
+        .peekable();
+
+    // figure out if we expect increasing or decreasing orders
+    let first_two = item_pairs.peek().expect("needs at least two elements");
+    let expected_change = if first_two.1 > first_two.0 {
+        ExpectLevelChanges::Increasing
+    } else {
+        ExpectLevelChanges::Decreasing
+    };
+
+    // check pairs and return as soon as we know something is wrong
+    for (first, second) in item_pairs {
+        let delta = second - first;
+        let delta_okay = if expected_change == ExpectLevelChanges::Increasing {
+            (1..=3).contains(&delta)
+        } else {
+            (-3..=-1).contains(&delta)
+        };
+        if !delta_okay {
+            return false
+        }
+    }
+    true
+}
+
+fn is_safe_with_one_removed(report: &[i32]) -> bool {
+    if is_safe(report, None) {
+        return true
+    }
+
+    for (idx, _) in report.iter().enumerate() {
+        if is_safe(report, Some(idx)) {
+            return true
+        }
+    }
+
+    false
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::{is_safe_with_one_removed, is_safe, Day02};
+
+    aoc_utils::aoc_tests!(Day02);
+
+    #[test]
+    fn detect_safe() {
+        let result = is_safe(&[38, 41, 40, 42, 45, 47, 50, 52], None);
+        assert!(!result);
+    }
+
+    #[test]
+    fn detect_safe_with_skip() {
+        let result = is_safe(&[38, 41, 40, 42, 45, 47, 50, 52], Some(2));
+        assert!(result);
+    }
+
+    #[test]
+    fn check_removals_needed() {
+        let result = is_safe_with_one_removed(&[38, 41, 40, 42, 45, 47, 50, 52]);
+        assert!(result);
+    }
+
+    #[test]
+    #[cfg(feature = "count-allocations")]
+    fn verify_no_allocations() {
+        let data = &[38, 41, 40, 42, 45, 47, 50, 52];
+        assert!(crate::allocations::assert_no_allocations(|| is_safe(data, Some(2))));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use anyhow::{Result};
+use aoc_utils::solution::Solution;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont
+}
+
+#[derive(Debug)]
+pub struct Input {
+    instructions: Vec<Instruction>
+}
+
+
+mod parse {
+    use anyhow::{Result};
+    use aoc_parse::{parser, Parser};
+    use aoc_parse::prelude::u64;
+    use super::{Input, Instruction};
+    use regex::Regex;
+
+
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
+        let re = Regex::new(r"mul\(\d{1,3},\d{1,3}\)|do\(\)|don't\(\)").unwrap();
+
+        let instruction_parser = parser!({
+            "mul(" p1:u64 "," p2:u64 ")" => Instruction::Mul(p1, p2),
+            "do()" => Instruction::Do,
+            "don't()" => Instruction::Dont,
+        });
+
+        let instructions = re.find_iter(raw_data)
+            .map(|m| m.as_str())
+            .map(|raw| instruction_parser.parse(raw).unwrap())
+            .collect::<Vec<Instruction>>();
+
+        Ok(Input {
+            instructions
+        })
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(input: &Input) -> Result<u64> {
+        debug!("{:?}", input);
+
+        let result = input.instructions.iter()
+            .fold(0, |total, inst| {
+                match inst {
+                    Instruction::Mul(a, b) => total + (a * b),
+                    _ => total // ignored
+                }
+            });
+
+        Ok(result)
+    }
+
+    fn part2(input: &Input) -> Result<u64> {
+        debug!("{:?}", input);
+
+        let mut total = 0;
+        let mut enabled = true;
+        for instruction in &input.instructions {
+            match instruction {
+                Instruction::Mul(a, b) if enabled => total += a * b,
+                Instruction::Do => enabled = true,
+                Instruction::Dont => enabled = false,
+                _ => () // do nothing
+            }
+        }
+
+        Ok(total)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day03;
+
+    aoc_utils::aoc_tests!(Day03);
+}
\ No newline at end of file
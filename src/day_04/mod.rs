@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use anyhow::{Result};
+use aoc_utils::map::{Line, Map, Position};
+use aoc_utils::solution::Solution;
+
+#[derive(Debug)]
+pub struct Input {
+    data: Map<char>
+}
+
+mod parse {
+    use anyhow::{Result, bail};
+    use aoc_utils::map::Map;
+    use super::Input;
+
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
+        let data = Map::parse_chars(raw_data, |c| {
+            if !c.is_ascii_uppercase() {
+                bail!("not an upper case letter")
+            }
+            Ok(c)
+        })?;
+
+        Ok(Input {
+            data
+        })
+    }
+}
+
+/// Represents a sequence of chars we want to search in another sequence but forwards and backwards.
+#[derive(Debug)]
+struct Needle {
+    needle: Vec<char>,
+    needle_rev: Vec<char>,
+}
+
+impl Needle {
+    fn new(s: &str) -> Self {
+        assert!(!s.is_empty());
+
+        let needle: Vec<char> = s.chars().collect();
+        let mut needle_rev: Vec<char> = s.chars().collect();
+        needle_rev.reverse();
+
+        Needle { needle, needle_rev }
+    }
+
+    fn len(&self) -> usize {
+        self.needle.len()
+    }
+
+    /// check if either direction matches the given slice
+    fn eq(&self, other: &[char]) -> bool {
+        self.needle == *other || self.needle_rev == *other
+    }
+
+    /// check if the needle starts or ends at the given position
+    fn is_match(&self, haystack: &[&char], index: usize) -> bool {
+        let slice: Option<Vec<char>> = haystack
+            .get(index..index + self.len())
+            .map(|char_slice| char_slice.iter().map(|c| **c).collect());
+
+        slice
+            .map(|slice| self.eq(&slice))
+            .unwrap_or(false)
+    }
+}
+
+/// find the number of (overlapping) matches of the needle in the haystack
+fn count_matches(haystack: &[&char], needle: &Needle) -> u32 {
+    let mut matches = 0u32;
+
+    for idx in 0..haystack.len() {
+        if needle.is_match(haystack, idx) {
+            debug!("Real match {:?} at {} in {:?}", needle, idx, haystack);
+            matches += 1
+            // count it
+        }
+    }
+
+    matches
+}
+
+
+
+/// Collect the chars of a row, column or diagonal so we can search in them
+fn chars(line: Line<'_, char>) -> Vec<&char> {
+    line.map(|(_, c)| c).collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(input: &Input) -> Result<u32> {
+        let needle = Needle::new("XMAS");
+        let map = &input.data;
+
+        // matches in horizontal, vertical and both diagonal directions
+        let total = map.rows()
+            .chain(map.columns())
+            .chain(map.diagonals())
+            .chain(map.anti_diagonals())
+            .map(|line| count_matches(&chars(line), &needle))
+            .sum();
+
+        Ok(total)
+    }
+
+    fn part2(input: &Input) -> Result<u32> {
+        let needle = Needle::new("MAS");
+
+        // find the middle position of the needle in all diagonals in both directions
+        let middles_ltr: HashSet<Position> = input.data.diagonals()
+            .flat_map(|diagonal| find_middle_positions(&diagonal.collect::<Vec<_>>(), &needle))
+            .collect();
+
+        let middles_rtl: HashSet<Position> = input.data.anti_diagonals()
+            .flat_map(|diagonal| find_middle_positions(&diagonal.collect::<Vec<_>>(), &needle))
+            .collect();
+
+        // check where the middles in both lists match and count how many there are
+        let total = middles_ltr.intersection(&middles_rtl).count();
+
+        Ok(total.try_into().unwrap())
+    }
+}
+
+/// find the middle positions of the needle and extract the original information
+fn find_middle_positions(haystack: &[(Position, &char)], needle: &Needle) -> Vec<Position> {
+    assert_eq!(needle.len() % 2, 1);
+    let middle_offset = needle.len() / 2;
+
+    let haystack_chars: Vec<&char> = haystack.iter().map(|(_, c)| *c).collect();
+    let mut middle_positions = vec![];
+    for idx in 0..haystack.len() {
+        if needle.is_match(&haystack_chars, idx) {
+            let (middle, _) = haystack[idx + middle_offset];
+            middle_positions.push(middle)
+        }
+    }
+
+    middle_positions
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day04;
+
+    aoc_utils::aoc_tests!(Day04);
+}
@@ -1,9 +1,7 @@
 use std::collections::{HashMap};
 use std::hash::{Hash, Hasher};
 use anyhow::{Result};
-
-#[macro_use]
-extern crate simple_log;
+use aoc_utils::solution::Solution;
 
 #[derive(Debug)]
 pub struct Rule {
@@ -37,17 +35,15 @@ pub struct Input {
 mod parse {
     use aoc_parse::{parser, prelude::*};
     use anyhow::{Result, Context};
-    use std::fs::read_to_string;
-    use crate::{Input, Rule};
+    use super::{Input, Rule};
 
-    pub fn parse_input(filename: &str) -> Result<Input> {
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
         let parser = parser!(
             section(lines(before:u32 "|" after:u32  => (before, after)))
             section(lines(repeat_sep(u32, ",")))
         );
 
-        let raw_data = read_to_string(filename)?;
-        let raw_parsed = parser.parse(&raw_data).context("parse error")?;
+        let raw_parsed = parser.parse(raw_data).context("parse error")?;
 
         Ok(Input {
             rules: raw_parsed.0.into_iter().enumerate().map(|(index, (before, after))| Rule { index, before, after }).collect(),
@@ -72,7 +68,7 @@ pub struct PageRules<'r> {
 }
 
 /// Build the PageRules object from the given input rules.
-fn build_page_rules(rules: &Vec<Rule>) -> PageRules {
+fn build_page_rules(rules: &[Rule]) -> PageRules<'_> {
     let mut page_rules = PageRules {
         before: HashMap::new(),
         after: HashMap::new(),
@@ -91,7 +87,7 @@ fn build_page_rules(rules: &Vec<Rule>) -> PageRules {
 }
 
 /// Find first rule violation of a print job given at set of rules.
-fn find_violation<'r>(print_job: &Vec<u32>, rules: &'r PageRules) -> Option<Violation<'r>> {
+fn find_violation<'r>(print_job: &[u32], rules: &'r PageRules) -> Option<Violation<'r>> {
     let empty_rules: Vec<&Rule> = Vec::new();
 
     // iterate over each page
@@ -111,7 +107,7 @@ fn find_violation<'r>(print_job: &Vec<u32>, rules: &'r PageRules) -> Option<Viol
                 .filter_map(|rule| if rule.after == *page_before {
                     Some(Violation {
                         at_index: page_idx,
-                        rule: *rule
+                        rule
                     })
                 } else {
                     None
@@ -129,7 +125,7 @@ fn find_violation<'r>(print_job: &Vec<u32>, rules: &'r PageRules) -> Option<Viol
                 .filter_map(|rule| if rule.before == *page_after {
                     Some(Violation {
                         at_index: page_idx,
-                        rule: *rule
+                        rule
                     })
                 } else {
                     None
@@ -146,14 +142,14 @@ fn find_violation<'r>(print_job: &Vec<u32>, rules: &'r PageRules) -> Option<Viol
 
 
 /// Fix a single rule violation by swapping the two pages that are in the wrong order.
-fn fix_violation(print_job: &mut Vec<u32>, violation: &Violation) {
+fn fix_violation(print_job: &mut [u32], violation: &Violation) {
     let page_at_index = print_job[violation.at_index];
     let other_page = if page_at_index == violation.rule.after {
         violation.rule.before
     } else {
         violation.rule.after
     };
-    let other_page_index = print_job.into_iter().position(|page| *page == other_page).unwrap();
+    let other_page_index = print_job.iter_mut().position(|page| *page == other_page).unwrap();
 
     debug!("fix: swap index {} with {}", violation.at_index, other_page_index);
 
@@ -161,14 +157,14 @@ fn fix_violation(print_job: &mut Vec<u32>, violation: &Violation) {
 }
 
 /// Fix a single print job by repeatedly fixing the first violation.
-fn fix_violations(print_job: &Vec<u32>, page_rules: &PageRules) -> Option<Vec<u32>> {
-    let mut fixed_job = print_job.clone();
+fn fix_violations(print_job: &[u32], page_rules: &PageRules) -> Option<Vec<u32>> {
+    let mut fixed_job = print_job.to_vec();
 
     let mut needed_fixing = false;
 
     // There is some big optimization potential here since we only ever use the first violation.
     // But the code is fast enough as is, so I won't do it.
-    while let Some(violation) = find_violation(&fixed_job, &page_rules) {
+    while let Some(violation) = find_violation(&fixed_job, page_rules) {
         needed_fixing = true;
         debug!("Job has violation: {violation:?}");
         fix_violation(&mut fixed_job, &violation);
@@ -176,79 +172,62 @@ fn fix_violations(print_job: &Vec<u32>, page_rules: &PageRules) -> Option<Vec<u3
 
     // We only return Some if we actually fixed something so the calling code can differentiate.
     if needed_fixing {
-        Some(fixed_job.clone())
+        Some(fixed_job)
     } else {
         None
     }
 }
 
-fn get_middle_page(print_job: &Vec<u32>) -> u32 {
+fn get_middle_page(print_job: &[u32]) -> u32 {
     *print_job.get(print_job.len() / 2).unwrap()
 }
 
-fn solve_part_1(filename: &str) -> Result<u32> {
-    let input = parse::parse_input(filename)?;
-
-    let page_rules = build_page_rules(&input.rules);
+pub struct Day05;
 
-    let mut total = 0u32;
-    for (job_idx, print_job) in input.print_jobs.iter().enumerate() {
-
-        if let Some(_) = find_violation(print_job, &page_rules) {
-            debug!("Job {job_idx} is bad");
-        } else {
-            debug!("Job {job_idx} is good");
-            total += get_middle_page(&print_job)
-        }
+impl Solution for Day05 {
+    type Input = Input;
+    type Answer = u32;
 
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
     }
 
-    Ok(total)
-}
+    fn part1(input: &Input) -> Result<u32> {
+        let page_rules = build_page_rules(&input.rules);
 
-fn solve_part_2(filename: &str) -> Result<u32> {
-    let input = parse::parse_input(filename)?;
-    let page_rules = build_page_rules(&input.rules);
+        let mut total = 0u32;
+        for (job_idx, print_job) in input.print_jobs.iter().enumerate() {
+
+            if find_violation(print_job, &page_rules).is_some() {
+                debug!("Job {job_idx} is bad");
+            } else {
+                debug!("Job {job_idx} is good");
+                total += get_middle_page(print_job)
+            }
 
-    let mut total = 0u32;
-    for print_job in input.print_jobs.iter() {
-        if let Some(fixed_job) = fix_violations(print_job, &page_rules) {
-            total += get_middle_page(&fixed_job)
         }
+
+        Ok(total)
     }
 
-    Ok(total)
-}
+    fn part2(input: &Input) -> Result<u32> {
+        let page_rules = build_page_rules(&input.rules);
 
-fn main() -> Result<()> {
-    simple_log::quick!("info");
+        let mut total = 0u32;
+        for print_job in input.print_jobs.iter() {
+            if let Some(fixed_job) = fix_violations(print_job, &page_rules) {
+                total += get_middle_page(&fixed_job)
+            }
+        }
 
-    info!("Result part 1: {}", solve_part_1("src/day_05/input.txt")?);
-    info!("Result part 2: {}", solve_part_2("src/day_05/input.txt")?);
-    Ok(())
+        Ok(total)
+    }
 }
 
-// part 2: 9985 too high
 
 #[cfg(test)]
 mod tests {
-    use ctor::ctor;
-    use crate::{solve_part_1, solve_part_2};
-
-    #[ctor]
-    fn init() {
-        simple_log::quick!("debug");
-    }
-
-    #[test]
-    fn solve_test_input_1() {
-        let result = solve_part_1("src/day_05/test_input.txt").unwrap();
-        assert_eq!(result, 143);
-    }
+    use super::Day05;
 
-    #[test]
-    fn solve_test_input_2() {
-        let result = solve_part_2("src/day_05/test_input.txt").unwrap();
-        assert_eq!(result, 123);
-    }
+    aoc_utils::aoc_tests!(Day05);
 }
\ No newline at end of file
@@ -0,0 +1,209 @@
+use std::cmp::PartialEq;
+use std::collections::HashSet;
+use std::iter::once;
+use std::fmt::{Display, Formatter, Write};
+use anyhow::{Result, bail, anyhow};
+use itertools::Itertools;
+use rayon::prelude::*;
+use aoc_utils::map::{Direction, Position};
+use aoc_utils::solution::Solution;
+
+type Map = aoc_utils::map::Map<Object>;
+
+#[derive(Debug, PartialEq, Clone)]
+#[derive(Default)]
+pub enum Object {
+    #[default]
+    Empty,
+    Item,
+    Guard(Direction),
+    Visited,
+    Blockage
+}
+
+
+impl Display for Object {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Object::Empty => f.write_char('.')?,
+            Object::Item => f.write_char('#')?,
+            Object::Guard(direction) => direction.fmt(f)?,
+            Object::Visited => f.write_char('X')?,
+            Object::Blockage => f.write_char('O')?,
+        }
+        Ok(())
+    }
+}
+
+mod parse {
+    use anyhow::{Result, bail};
+    use aoc_utils::map::{Direction,Map};
+    use super::Object;
+
+    pub fn parse_input(raw_data: &str) -> Result<Map<Object>> {
+        Map::parse_chars(raw_data, |c| {
+            Ok(match c {
+                '.' => Object::Empty,
+                '#' => Object::Item,
+                '^' => Object::Guard(Direction::Up),
+                '>' => Object::Guard(Direction::Right),
+                '<' => Object::Guard(Direction::Left),
+                'v' => Object::Guard(Direction::Down),
+                'X' => Object::Visited,
+                _ => bail!("not a map object")
+            })
+        })
+    }
+}
+
+
+/// Find the guard on the map and the direction it's looking at.
+fn find_guard(map: &Map) -> Result<(Position, Direction)> {
+    match map.find(|object| matches!(object, Object::Guard(_))) {
+        Some((position, Object::Guard(view_direction))) => Ok((position, *view_direction)),
+        _ => bail!("No guard found")
+    }
+}
+
+
+/// Find the position at which the guard will run into an object (not the position of the object itself!).
+/// Returns None if there is nothing blocking the guard.
+fn find_blocking_object(start_position: Position, view_direction: &Direction, map: &Map) -> Option<Position> {
+    // cast ray in that direction, the guard stops right before the first object
+    map.cast_until(&start_position, *view_direction, |_, object| *object == Object::Item || *object == Object::Blockage)
+        .and_then(|(object_position, _)| map.new_position(&object_position, &view_direction.opposite()))
+}
+
+/// Move the guard from a current position to the target.
+fn move_guard(map: &mut Map, current_position: Position, new_position: Position) -> Result<Direction> {
+    // grab the guard from the map and put default in place
+    let guard = map.replace(&current_position, Object::default());
+
+    let new_direction = match guard {
+        // the new guard has already turned
+        Object::Guard(current_direction) => current_direction.turn_clockwise(),
+        _ => bail!("Guard not currently at position {current_position:?}")
+    };
+
+    // place new guard on map
+    map.set(&new_position, Object::Guard(new_direction));
+
+    Ok(new_direction)
+}
+
+/// Mark the path from a starting position until we reach the guard. This traces the path it must
+/// have walked.
+fn mark_visited(map: &mut Map, start: Position, direction: &Direction) {
+    for position in once(start).chain(map.ray_positions(&start, *direction)) {
+        let object_at = map.get_mut(&position).expect("position is valid");
+        if let Object::Guard(_) = object_at {
+            break
+        }
+        *object_at = Object::Visited;
+    }
+}
+
+/// Mark the exit path of the guard from it's current position.
+fn mark_exit(map: &mut Map, start: Position, direction: &Direction) {
+    for position in once(start).chain(map.ray_positions(&start, *direction)) {
+        map.set(&position, Object::Visited);
+    }
+}
+
+/// Simulation step
+fn do_step(map: &mut Map, current_position: Position, current_direction: &Direction) -> Result<Option<(Position, Direction)>> {
+    if let Some(new_guard_position) = find_blocking_object(current_position, current_direction, map) {
+        let new_direction = move_guard(map, current_position, new_guard_position)?;
+        mark_visited(map, current_position, current_direction);
+        //debug!("{}", map);
+        Ok(Some((new_guard_position, new_direction)))
+    } else {
+        mark_exit(map, current_position, current_direction);
+        Ok(None)
+    }
+}
+
+/// Find an exit path but optionally check for endless loops.
+fn find_exit_path(map: &mut Map, loop_detection: bool) -> Result<Option<Vec<Position>>> {
+    let (mut position, mut view_direction) = find_guard(map)?;
+
+    let mut already_visited: HashSet<(Position, Direction)> = HashSet::new();
+
+    // simulate steps the guard takes until it leaved the map
+    while let Some(next_step) =  do_step(map, position, &view_direction)? {
+        if loop_detection {
+            if already_visited.iter().contains(&next_step) {
+                return Err(anyhow!("Loop detected, already visited {next_step:?}"))
+            }
+            already_visited.insert(next_step);
+        }
+
+        position = next_step.0;
+        view_direction = next_step.1;
+
+    }
+
+    let result: Vec<Position> = map.find_all(|object| *object == Object::Visited)
+        .map(|(position, _)| position)
+        .collect();
+
+    Ok(Some(result))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Map;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<Map> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(map: &Map) -> Result<u32> {
+        let mut map = map.clone();
+        debug!("Starting map {}", map);
+
+        let path = find_exit_path(&mut map, false).unwrap().unwrap();
+        debug!("Done! {}", map);
+
+        Ok(path.len() as u32)
+    }
+
+    fn part2(map: &Map) -> Result<u32> {
+        // get the original exit path of the guard
+        let mut original_map = map.clone();
+        let exit_path = find_exit_path(&mut original_map, false).unwrap().unwrap();
+
+        // loop over all positions (in parallel for speeeed)
+        let looping_paths = exit_path.into_par_iter()
+            .filter(|position| match map.get(position) {
+                Some(Object::Guard(_)) => false, // skip guard position
+                Some(_) => true, // falls thought
+                None => panic!("Exit path positions should always be valid")
+            })
+            .fold(|| 0_u32, |blockage_count: u32, position: Position| {
+                // create a blockage at the position
+                let mut map_with_blockage = map.clone();
+                map_with_blockage.set(&position, Object::Blockage);
+
+                // if the path now loops that is a valid blockage
+                if find_exit_path(&mut map_with_blockage, true).is_err() {
+                    blockage_count + 1
+                } else {
+                    blockage_count
+                }
+            })
+            .sum::<u32>();
+
+        Ok(looping_paths)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day06;
+
+    aoc_utils::aoc_tests!(Day06);
+}
\ No newline at end of file
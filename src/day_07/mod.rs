@@ -1,10 +1,8 @@
 use std::cmp::PartialEq;
 use anyhow::{Result};
 use itertools::{Itertools, repeat_n};
-use crate::Operator::{Add, Multiply};
-
-#[macro_use]
-extern crate simple_log;
+use aoc_utils::solution::Solution;
+use self::Operator::{Add, Multiply};
 
 
 #[derive(Debug)]
@@ -19,7 +17,7 @@ pub struct Equation {
 }
 
 #[derive(Debug)]
-pub struct Solution {
+pub struct EquationSolution {
     equation: Equation,
 
     #[allow(dead_code)] // just used in tests
@@ -35,21 +33,19 @@ pub enum Operator {
 
 #[derive(Debug)]
 pub enum RecResult {
-    Found(Solution),
+    Found(EquationSolution),
     TooLarge
 }
 
 mod parse {
     use aoc_parse::{parser, prelude::*};
     use anyhow::{Result, Context};
-    use std::fs::read_to_string;
-    use crate::{Equation, Input};
+    use super::{Equation, Input};
 
-    pub fn parse_input(filename: &str) -> Result<Input> {
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
         let parser = parser!(lines(result:u64 ": " numbers:repeat_sep(u64, " ") => Equation { desired_result: result, numbers }));
 
-        let raw_data = read_to_string(filename)?;
-        let equations = parser.parse(&raw_data).context("parse error")?;
+        let equations = parser.parse(raw_data).context("parse error")?;
 
         Ok(Input {
             equations
@@ -58,10 +54,10 @@ mod parse {
 }
 
 /// Find a solution for the input given a set of operators we can use
-fn solve(input: Input, possible_operators: Vec<Operator>) -> Result<u64> {
+fn solve(input: &Input, possible_operators: Vec<Operator>) -> Result<u64> {
     let mut total_results = 0u64;
     for equation in input.equations.iter() {
-        if let Some(solution) = find_solution(&equation, &possible_operators) {
+        if let Some(solution) = find_solution(equation, &possible_operators) {
             total_results += solution.equation.desired_result;
         }
     }
@@ -70,14 +66,14 @@ fn solve(input: Input, possible_operators: Vec<Operator>) -> Result<u64> {
 }
 
 /// Find a potential solution by checking all possible operator combinations.
-fn find_solution(equation: &Equation, possible_operators: &Vec<Operator>) -> Option<Solution> {
+fn find_solution(equation: &Equation, possible_operators: &Vec<Operator>) -> Option<EquationSolution> {
     let all_operators= repeat_n(possible_operators, equation.numbers.len() - 1)
         .multi_cartesian_product();
 
     for operators in all_operators {
         let result = compute_result(equation, &operators);
         if result == equation.desired_result {
-            let solution = Solution {
+            let solution = EquationSolution {
                 equation: equation.clone(),
                 operators: operators.iter().map(|o| (*o).clone()).collect()
             };
@@ -107,48 +103,32 @@ fn compute_result(equation: &Equation, operators: &Vec<&Operator>) -> u64 {
         }).unwrap()
 }
 
-fn solve_part_1(filename: &str) -> Result<u64> {
-    let input = parse::parse_input(filename)?;
-    solve(input, vec![Add, Multiply])
-}
+pub struct Day07;
 
-fn solve_part_2(filename: &str) -> Result<u64> {
-    let input = parse::parse_input(filename)?;
-    solve(input, vec![Add, Multiply, Operator::Concat])
-}
+impl Solution for Day07 {
+    type Input = Input;
+    type Answer = u64;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
 
-fn main() -> Result<()> {
-    simple_log::quick!("info");
+    fn part1(input: &Input) -> Result<u64> {
+        solve(input, vec![Add, Multiply])
+    }
 
-    info!("Result part 1: {}", solve_part_1("src/day_07/input.txt")?);
-    info!("Result part 2: {}", solve_part_2("src/day_07/input.txt")?);
-    Ok(())
+    fn part2(input: &Input) -> Result<u64> {
+        solve(input, vec![Add, Multiply, Operator::Concat])
+    }
 }
 
 
 #[cfg(test)]
 mod tests {
-    use ctor::ctor;
-    use crate::{Equation, find_solution, solve_part_1, solve_part_2};
-    use crate::Operator::{Add, Multiply};
+    use super::{Equation, find_solution, Day07};
+    use super::Operator::{Add, Multiply};
 
-    #[ctor]
-    fn init() {
-        simple_log::quick!("debug");
-    }
-
-    #[test]
-    fn solve_test_input_1() {
-        let result = solve_part_1("src/day_07/test_input.txt").unwrap();
-        assert_eq!(result, 3749);
-    }
-
-
-    #[test]
-    fn solve_test_input_2() {
-        let result = solve_part_2("src/day_07/test_input.txt").unwrap();
-        assert_eq!(result, 11387);
-    }
+    aoc_utils::aoc_tests!(Day07);
 
     #[test]
     fn solve_find_solution() {
@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter, Write};
+use anyhow::{Result};
+use itertools::Itertools;
+use aoc_utils::map::{Point, Position};
+use aoc_utils::solution::Solution;
+
+type Map = aoc_utils::map::Map<Object>;
+
+#[derive(Debug, Clone)]
+pub enum Object {
+    Empty,
+    Antenna { frequency: char },
+    AntiNode
+}
+
+impl Display for Object {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Object::Empty => f.write_char('.')?,
+            Object::Antenna { frequency} => f.write_char(*frequency)?,
+            Object::AntiNode => f.write_char('#')?,
+        }
+        Ok(())
+    }
+}
+
+
+mod parse {
+    use anyhow::{Result, bail};
+    use super::{Map, Object};
+
+    pub fn parse_input(raw_data: &str) -> Result<Map> {
+        Map::parse_chars(raw_data, |c| {
+            Ok(match c {
+                '.' => Object::Empty,
+                '#' => Object::AntiNode,
+                freq if freq.is_ascii_alphanumeric() => Object::Antenna { frequency: freq },
+                _ => bail!("not a map object")
+            })
+        })
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<Map> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(map: &Map) -> Result<u32> {
+        let antennas_by_frequency = find_all_antennas(map);
+
+        // we use a set to de-duplicate
+        let antinodes: HashSet<Position> = antennas_by_frequency.into_values()
+            .flat_map(|antennas| {
+                compute_all_antinodes(antennas, map)
+            }).collect();
+
+        Ok(antinodes.len() as u32)
+    }
+
+    fn part2(map: &Map) -> Result<u32> {
+        let antennas_by_frequency = find_all_antennas(map);
+
+        // we use a set to de-duplicate
+        let harmonics: HashSet<Position> = antennas_by_frequency.into_values()
+            .flat_map(|antennas| {
+                 compute_all_harmonics(antennas, map)
+            }).collect();
+
+        Ok(harmonics.len() as u32)
+
+    }
+}
+
+fn find_all_antennas(map: &Map) -> HashMap<&char, Vec<Position>> {
+    map.group_positions_by(|obj| match obj {
+        Object::Antenna { frequency } => Some(frequency),
+        _ => None
+    })
+}
+
+fn compute_all_antinodes(antennas: Vec<Position>, map: &Map) -> Vec<Position> {
+      antennas.iter().combinations(2)
+        .flat_map(|pair| compute_antinodes(pair.first().unwrap(), pair.get(1).unwrap(), map))
+        .collect()
+}
+
+fn compute_antinodes(a: &Position, b: &Position, map: &Map) -> Vec<Position> {
+    // the antinodes are as far away from one antenna as the other antenna is, on both sides
+    [(a, b), (b, a)].into_iter()
+        .filter_map(|(from, to)| map.ray_positions(to, Point::from(*to) - Point::from(*from)).next())
+        .collect()
+}
+
+
+fn compute_all_harmonics(antennas: Vec<Position>, map: &Map) -> Vec<Position> {
+    antennas.iter().combinations(2)
+        .flat_map(|pair| compute_harmonics(pair.first().unwrap(), pair.get(1).unwrap(), map))
+        .collect()
+}
+
+fn compute_harmonics(a: &Position, b: &Position, map: &Map) -> Vec<Position> {
+    let mut harmonics: Vec<Position> = Vec::new();
+
+    // walk from each antenna away from the other one, the antennas themselves are also harmonics
+    for (start, other) in [(a, b), (b, a)] {
+        harmonics.push(*start);
+        harmonics.extend(map.ray_positions(start, Point::from(*start) - Point::from(*other)));
+    }
+
+    harmonics
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day08;
+
+    aoc_utils::aoc_tests!(Day08);
+}
\ No newline at end of file
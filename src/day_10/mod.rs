@@ -0,0 +1,98 @@
+use std::fmt::{Debug, Display, Formatter, Write};
+use anyhow::{Result};
+use aoc_utils::map::{Map, Position};
+use aoc_utils::search::{count_paths, PathCounts};
+use aoc_utils::solution::Solution;
+
+type InputMap = Map<InputItem>;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum InputItem {
+    Empty,
+    Tile(usize),
+}
+
+impl Display for InputItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputItem::Empty => f.write_char('.')?,
+            InputItem::Tile(height) => std::fmt::Display::fmt(&height, f)?,
+        }
+        Ok(())
+    }
+}
+
+mod parse {
+    use anyhow::{Result, Context};
+    use super::{InputItem, InputMap};
+
+    pub fn parse_input(raw_data: &str) -> Result<InputMap> {
+        InputMap::parse_chars(raw_data, |c| match c {
+            '.' => Ok(InputItem::Empty),
+            _ => c.to_digit(10)
+                .map(|height| InputItem::Tile(height as usize))
+                .context("not a height")
+        })
+    }
+
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = InputMap;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<InputMap> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(map: &InputMap) -> Result<u32> {
+        debug!("Start map: {map}");
+
+        // the score of a trail head is the number of peaks it is connected to
+        let sum_of_scores = count_trails(map)?.connected().count();
+
+        Ok(sum_of_scores as u32)
+    }
+
+    fn part2(map: &InputMap) -> Result<u32> {
+        debug!("Start map: {map}");
+
+        // the rating of a trail head is the number of distinct trails from it
+        let total = count_trails(map)?.total();
+
+        Ok(total as u32)
+    }
+}
+
+/// Count the trails between every trail head and every peak.
+fn count_trails(map: &InputMap) -> Result<PathCounts<Position>> {
+    let trail_heads = map.find_all(|item| *item == InputItem::Tile(0))
+        .map(|(position, _)| position);
+
+    Ok(count_paths(trail_heads, |current| steps_up(map, current), |position| height(map, position) == 9)?)
+}
+
+/// We only follow the path "up", one height at a time.
+fn steps_up<'m>(map: &'m InputMap, current: &Position) -> impl Iterator<Item = Position> + 'm {
+    let current_height = height(map, current);
+    map.neighbors(current)
+        .filter(move |(_, _, neighbor)| **neighbor == InputItem::Tile(current_height + 1))
+        .map(|(neighbor_position, _, _)| neighbor_position)
+}
+
+fn height(map: &InputMap, position: &Position) -> usize {
+    match map.get(position).unwrap() {
+        InputItem::Tile(height) => *height,
+        _ => panic!("Can't handle empty tile at {:?}", position)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+
+    aoc_utils::aoc_tests!(Day10);
+}
\ No newline at end of file
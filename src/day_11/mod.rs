@@ -1,9 +1,7 @@
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use anyhow::Result;
-
-#[macro_use]
-extern crate simple_log;
+use aoc_utils::solution::Solution;
 
 #[derive(Debug)]
 pub struct Input {
@@ -13,14 +11,12 @@ pub struct Input {
 mod parse {
     use aoc_parse::{parser, prelude::*};
     use anyhow::{Result, Context};
-    use std::fs::read_to_string;
-    use crate::Input;
+    use super::Input;
 
-    pub fn parse_input(filename: &str) -> Result<Input> {
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
         let parser = parser!(repeat_sep(u64, " "));
 
-        let raw_data = read_to_string(filename)?;
-        let numbers = parser.parse(&raw_data).context("parse error")?;
+        let numbers = parser.parse(raw_data).context("parse error")?;
 
         Ok(Input {
             numbers
@@ -54,12 +50,10 @@ impl Display for PartialResult {
     }
 }
 
-fn solve(filename: &str, blink_count: usize) -> Result<usize> {
-    let input = parse::parse_input(filename)?;
-
+fn solve(input: &Input, blink_count: usize) -> Result<usize> {
     // put input into partial result
     let mut state = PartialResult::default();
-    for start_number in input.numbers {
+    for start_number in input.numbers.iter().copied() {
         match start_number {
             0 => { state.zeros += 1; },
             1 => { state.zeros += 1; },
@@ -69,10 +63,8 @@ fn solve(filename: &str, blink_count: usize) -> Result<usize> {
 
     debug!("{}", state);
     for idx in 0..blink_count {
-        let mut new_state = PartialResult::default();
-
         // zeros become ones
-        new_state.ones = state.zeros;
+        let mut new_state = PartialResult { ones: state.zeros, ..Default::default() };
 
         // ones become 2024
         new_state.add_number(2024, state.ones);
@@ -111,29 +103,36 @@ fn solve(filename: &str, blink_count: usize) -> Result<usize> {
     Ok(state.total())
 }
 
+pub struct Day11;
 
-fn main() -> Result<()> {
-    simple_log::quick!("info");
+impl Solution for Day11 {
+    type Input = Input;
+    type Answer = usize;
 
-    info!("Result part 1: {}", solve("src/day_11/input.txt", 25)?);
-    info!("Result part 2: {}", solve("src/day_11/input.txt", 75)?);
-    Ok(())
-}
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
 
+    fn part1(input: &Input) -> Result<usize> {
+        solve(input, 25)
+    }
+
+    fn part2(input: &Input) -> Result<usize> {
+        solve(input, 75)
+    }
+}
 
 #[cfg(test)]
 mod tests {
-    use ctor::ctor;
-    use crate::{solve};
+    use aoc_utils::solution::{read_input, Solution};
+    use super::{solve, Day11};
 
-    #[ctor]
-    fn init() {
-        simple_log::quick!("debug");
-    }
+    aoc_utils::aoc_tests!(Day11);
 
     #[test]
     fn solve_test_input() {
-        assert_eq!(solve("src/day_11/test_input.txt", 6).unwrap(), 22);
+        let input = Day11::parse(&read_input("src/day_11/test_input.txt").unwrap()).unwrap();
+        assert_eq!(solve(&input, 6).unwrap(), 22);
     }
 
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+use std::cmp::PartialEq;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Write};
+use anyhow::{Result, bail};
+use aoc_utils::map::{Map, Position};
+use aoc_utils::search::bfs;
+use aoc_utils::solution::Solution;
+
+#[derive(Debug, PartialEq)]
+pub struct Plant(char);
+
+impl TryFrom<char> for Plant {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> Result<Plant> {
+        if !c.is_ascii_uppercase() {
+            bail!("plants are upper case letters")
+        }
+        Ok(Plant(c))
+    }
+}
+
+impl Display for Plant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.0)
+    }
+}
+
+type InputMap = Map<Plant>;
+
+#[derive(Debug)]
+pub struct Input {
+    map: InputMap
+}
+
+mod parse {
+    use anyhow::Result;
+    use aoc_utils::map::Map;
+    use super::Input;
+
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
+        Ok(Input {
+            map: Map::parse(raw_data)?
+        })
+    }
+}
+
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Input;
+    type Answer = usize;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(input: &Input) -> Result<usize> {
+        debug!("Input map: {}", input.map);
+
+        let mut all_different_region: HashSet<Region> = HashSet::new();
+        let mut all_positions_seen:  HashSet<Position> = HashSet::new();
+        for (position, _) in input.map.iter_objects() {
+            if all_positions_seen.contains(&position) {
+                // shortcut
+                continue;
+            }
+
+            let region = determine_region(&input.map, position);
+
+            if !all_different_region.contains(&region) {
+                debug!("New region for {:?}: {:?}", position, region);
+                all_positions_seen.extend(&region.positions);
+                all_different_region.insert(region);
+            }
+        }
+
+        debug!("Found {} regions", all_different_region.len());
+
+        let total = all_different_region.iter()
+            .map(|region: &Region| calc_area(region) * calc_perimeter_length(&input.map, region))
+            .sum();
+
+        Ok(total)
+    }
+
+    fn part2(input: &Input) -> Result<usize> {
+        debug!("{:?}", input);
+
+        bail!("part 2 is not solved yet")
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct Region {
+    plant_type: char,
+    positions: Vec<Position>
+}
+
+impl Region {
+    fn new(plant_type: char, mut positions: Vec<Position>) -> Region {
+        positions.sort();
+        Region {
+            plant_type,
+            positions
+        }
+    }
+}
+
+fn determine_region(map: &InputMap, start_position: Position) -> Region {
+    let current_plant = map.get(&start_position).unwrap();
+    let same_plant_neighbors = |position: &Position| map.neighbors(position)
+        .filter(|(_, _, plant)| *plant == current_plant)
+        .map(|(neighbor_position, _, _)| neighbor_position);
+
+    let search = bfs([start_position], same_plant_neighbors, |_| false);
+
+    Region::new(current_plant.0, search.distances().keys().copied().collect())
+}
+
+fn calc_area(region: &Region) -> usize {
+    region.positions.len()
+}
+
+/// For each block we can determine how many perimeters it contributes:
+/// The perimeter_contribution is always neighbors_count - 4
+fn calc_perimeter_length(map: &InputMap, region: &Region) -> usize {
+
+    let mut perimeters = 0usize;
+    for region_point in &region.positions {
+
+        let neighbors_same_region = map.neighbors(region_point)
+            .filter(|(_, _, plant)| plant.0 == region.plant_type)
+            .count();
+        assert!(neighbors_same_region <= 4);
+
+        perimeters += 4 - neighbors_same_region
+    }
+
+    perimeters
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day12;
+
+    aoc_utils::aoc_tests!(Day12);
+}
\ No newline at end of file
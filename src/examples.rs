@@ -0,0 +1,77 @@
+use std::fs::{read_dir, read_to_string};
+use std::path::Path;
+use anyhow::{Result, Context};
+use aoc_utils::solution::Part;
+use crate::manifest::{Example, Expected, Manifest, MANIFEST_FILE};
+use crate::runner::{solve_day, Day, InputSource};
+
+/// Example files are named `test_input*.txt`, files following this convention that are not listed in the
+/// manifest are reported.
+const EXAMPLE_PREFIX: &str = "test_input";
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+}
+
+impl Manifest {
+    /// Load the manifest of a day. Days without a manifest have no examples.
+    pub fn load(day: &Day) -> Result<Manifest> {
+        let path = format!("{}/{MANIFEST_FILE}", day.directory());
+        if !Path::new(&path).exists() {
+            return Ok(Manifest::default())
+        }
+        let raw_data = read_to_string(&path).with_context(|| format!("Could not read {path}"))?;
+        toml::from_str(&raw_data).with_context(|| format!("Could not parse {path}"))
+    }
+
+    /// The example files in the day folder that follow the naming convention but are not listed in the manifest.
+    pub fn unlisted_files(&self, day: &Day) -> Result<Vec<String>> {
+        let mut unlisted = Vec::new();
+        for entry in read_dir(day.directory()).with_context(|| format!("Could not list {}", day.directory()))? {
+            let file = entry?.file_name().to_string_lossy().to_string();
+            let is_example = file.starts_with(EXAMPLE_PREFIX) && file.ends_with(".txt");
+            if is_example && !self.examples.iter().any(|example| example.file == file) {
+                unlisted.push(file);
+            }
+        }
+        unlisted.sort();
+        Ok(unlisted)
+    }
+}
+
+/// Solve all examples of the given days and compare the results with the expected answers from the manifests.
+/// Returns the number of results that are wrong.
+pub fn check(days: &[&Day]) -> Result<usize> {
+    let mut wrong_results = 0;
+
+    for day in days {
+        let manifest = Manifest::load(day)?;
+        for file in manifest.unlisted_files(day)? {
+            warn!("Day {:02} example {file} is not listed in {MANIFEST_FILE}", day.number);
+        }
+
+        for example in &manifest.examples {
+            let parts: Vec<_> = Part::BOTH.into_iter()
+                .filter(|part| example.expected(*part).is_some())
+                .collect();
+            let input = InputSource::File(format!("{}/{}", day.directory(), example.file));
+
+            for (part, result) in solve_day(day, &parts, &input)? {
+                let expected = example.expected(part).expect("only parts with an expected answer are solved");
+                if result == expected.to_string() {
+                    info!("Day {:02} example {} part {part}: {result}", day.number, example.file);
+                } else {
+                    error!("Day {:02} example {} part {part}: {result}, expected {expected}", day.number, example.file);
+                    wrong_results += 1;
+                }
+            }
+        }
+    }
+
+    Ok(wrong_results)
+}
@@ -0,0 +1,102 @@
+use std::fs::{create_dir_all, read_to_string, remove_file, rename, write};
+use std::path::{Path, PathBuf};
+use anyhow::{Result, Context};
+use crate::http::{require_session, Transport, BASE_URL};
+
+/// The directory downloaded inputs are cached in, relative to the repository root. It is not checked in.
+pub const CACHE_DIR: &str = ".aoc_cache";
+
+/// Where the downloaded input of a day is stored when the default [CACHE_DIR] is used.
+pub fn cached_input(day: u8) -> String {
+    format!("{CACHE_DIR}/{}", input_file(day))
+}
+
+fn input_file(day: u8) -> String {
+    format!("inputs/day_{day:02}.txt")
+}
+
+/// Downloads puzzle inputs into a cache directory. An input is only ever downloaded once, later fetches use
+/// the cached file.
+pub struct Fetcher<T: Transport> {
+    transport: T,
+    session: Option<String>,
+    cache_dir: PathBuf,
+}
+
+impl <T: Transport> Fetcher<T> {
+    pub fn new(transport: T, session: Option<String>, cache_dir: impl AsRef<Path>) -> Fetcher<T> {
+        Fetcher { transport, session, cache_dir: cache_dir.as_ref().to_path_buf() }
+    }
+
+    /// The path of the cached input of a day, it only exists once the input was fetched.
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.cache_dir.join(input_file(day))
+    }
+
+    pub fn is_cached(&self, day: u8) -> bool {
+        self.input_path(day).exists()
+    }
+
+    /// Return the input of a day, downloading it first if it is not cached yet.
+    pub fn fetch(&self, day: u8) -> Result<String> {
+        let path = self.input_path(day);
+        if path.exists() {
+            return read_to_string(&path).with_context(|| format!("Could not read {}", path.display()))
+        }
+
+        let session = require_session(self.session.as_deref())?;
+        let raw_data = self.transport.get(&format!("{BASE_URL}/day/{day}/input"), session)
+            .with_context(|| format!("Could not download the input of day {day:02}"))?;
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent).with_context(|| format!("Could not create {}", parent.display()))?;
+        }
+        // written next to the final file and renamed, so an interrupted download never leaves a partial input
+        // in the cache that would be used from then on
+        let partial_path = path.with_extension(format!("txt.{}.partial", std::process::id()));
+        write(&partial_path, &raw_data).with_context(|| format!("Could not write {}", partial_path.display()))?;
+        if let Err(error) = rename(&partial_path, &path) {
+            let _ = remove_file(&partial_path);
+            return Err(error).with_context(|| format!("Could not move the input to {}", path.display()))
+        }
+        Ok(raw_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{read_dir, remove_dir_all};
+    use crate::http::FixtureTransport;
+    use super::Fetcher;
+
+    #[test]
+    fn download_only_once() {
+        let cache_dir = temp_dir().join(format!("aoc_fetch_test_{}", std::process::id()));
+        let transport = FixtureTransport::new("125 17\n");
+        let fetcher = Fetcher::new(&transport, Some("secret".to_string()), &cache_dir);
+
+        assert!(!fetcher.is_cached(11));
+        assert_eq!(fetcher.fetch(11).unwrap(), "125 17\n");
+        assert!(fetcher.is_cached(11));
+        let cached_files: Vec<_> = read_dir(fetcher.input_path(11).parent().unwrap()).unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        assert_eq!(cached_files, vec!["day_11.txt"]);
+        assert_eq!(fetcher.fetch(11).unwrap(), "125 17\n");
+        assert_eq!(*transport.requests.borrow(), vec!["https://adventofcode.com/2024/day/11/input"]);
+
+        remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn require_session_for_download() {
+        let cache_dir = temp_dir().join(format!("aoc_fetch_test_no_session_{}", std::process::id()));
+        let transport = FixtureTransport::new("125 17\n");
+        let fetcher = Fetcher::new(&transport, None, &cache_dir);
+
+        let error = fetcher.fetch(11).unwrap_err();
+        assert!(error.to_string().contains("No session cookie found"));
+        assert!(transport.requests.borrow().is_empty());
+    }
+}
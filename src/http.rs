@@ -0,0 +1,106 @@
+#[cfg(test)]
+use std::cell::RefCell;
+use std::env;
+use std::fs::read_to_string;
+use std::path::Path;
+use anyhow::{Result, Context, bail};
+
+/// The puzzles of this year are at `{BASE_URL}/day/N`.
+pub const BASE_URL: &str = "https://adventofcode.com/2024";
+
+/// Advent of Code asks automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/martinth/advent-of-code-2024 aoc runner";
+
+/// The environment variable that holds the session cookie.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// The file the session cookie is read from if the environment variable is not set, it is not checked in.
+pub const SESSION_FILE: &str = ".session";
+
+/// How requests to Advent of Code are made, so tests can replace it with fixtures instead of a server.
+pub trait Transport {
+    /// Request the url with the session cookie and return the body. Unsuccessful responses are errors.
+    fn get(&self, url: &str, session: &str) -> Result<String>;
+
+    /// Post the form fields to the url with the session cookie and return the body.
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String>;
+}
+
+/// Answers every request with the same body and remembers the requests, forms are appended to the url like a
+/// query. Only the session `secret` is accepted.
+#[cfg(test)]
+pub struct FixtureTransport {
+    body: String,
+    pub requests: RefCell<Vec<String>>,
+}
+
+#[cfg(test)]
+impl FixtureTransport {
+    pub fn new(body: &str) -> FixtureTransport {
+        FixtureTransport { body: body.to_string(), requests: RefCell::new(Vec::new()) }
+    }
+
+    fn respond(&self, request: String, session: &str) -> Result<String> {
+        if session != "secret" {
+            bail!("400 Bad Request")
+        }
+        self.requests.borrow_mut().push(request);
+        Ok(self.body.clone())
+    }
+}
+
+#[cfg(test)]
+impl Transport for &FixtureTransport {
+    fn get(&self, url: &str, session: &str) -> Result<String> {
+        self.respond(url.to_string(), session)
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String> {
+        let form = form.iter().map(|(key, value)| format!("{key}={value}")).collect::<Vec<_>>().join("&");
+        self.respond(format!("{url}?{form}"), session)
+    }
+}
+
+/// The transport that talks to the real Advent of Code server.
+pub struct HttpTransport;
+
+impl Transport for HttpTransport {
+    fn get(&self, url: &str, session: &str) -> Result<String> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("Request to {url} failed"))?;
+        response.into_string().with_context(|| format!("Could not read the response of {url}"))
+    }
+
+    fn post_form(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String> {
+        let response = ureq::post(url)
+            .set("Cookie", &format!("session={session}"))
+            .set("User-Agent", USER_AGENT)
+            .send_form(form)
+            .with_context(|| format!("Request to {url} failed"))?;
+        response.into_string().with_context(|| format!("Could not read the response of {url}"))
+    }
+}
+
+/// Read the session cookie from [SESSION_VARIABLE] or, if that is not set, from [SESSION_FILE]. Returns `None`
+/// if neither exists, since it is only needed once something actually has to be requested.
+pub fn load_session() -> Result<Option<String>> {
+    if let Ok(session) = env::var(SESSION_VARIABLE) {
+        return Ok(Some(session.trim().to_string()))
+    }
+    if !Path::new(SESSION_FILE).exists() {
+        return Ok(None)
+    }
+    let session = read_to_string(SESSION_FILE).with_context(|| format!("Could not read {SESSION_FILE}"))?;
+    Ok(Some(session.trim().to_string()))
+}
+
+/// The session cookie or an error explaining how to provide it.
+pub fn require_session(session: Option<&str>) -> Result<&str> {
+    match session {
+        Some(session) if !session.is_empty() => Ok(session),
+        _ => bail!("No session cookie found, set {SESSION_VARIABLE} or put it into {SESSION_FILE}"),
+    }
+}
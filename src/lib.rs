@@ -0,0 +1,46 @@
+#[macro_use]
+extern crate simple_log;
+
+#[cfg(feature = "count-allocations")]
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod fetch;
+pub mod http;
+pub mod manifest;
+pub mod runner;
+pub mod submit;
+
+/// Declares the module of every day and registers its solution in [DAYS].
+macro_rules! days {
+    ($($number:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// All days that have a solution, ordered by day number.
+        pub const DAYS: &[runner::Day] = &[
+            $(runner::Day { number: $number, solution: &$module::$solution },)*
+        ];
+    };
+}
+
+days! {
+    1 => day_01::Day01,
+    2 => day_02::Day02,
+    3 => day_03::Day03,
+    4 => day_04::Day04,
+    5 => day_05::Day05,
+    6 => day_06::Day06,
+    7 => day_07::Day07,
+    8 => day_08::Day08,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    // new days are inserted above this line by the template hook
+}
+
+#[cfg(test)]
+#[ctor::ctor]
+fn init() {
+    simple_log::quick!("debug");
+}
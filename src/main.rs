@@ -0,0 +1,195 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use anyhow::{Result, bail};
+use clap::{Parser, Subcommand};
+use aoc_utils::solution::Part;
+use advent_of_code_2024::answers::{Answers, Feedback, ANSWERS_FILE};
+use advent_of_code_2024::{bench, examples, http};
+use advent_of_code_2024::fetch::{Fetcher, CACHE_DIR};
+use advent_of_code_2024::http::HttpTransport;
+use advent_of_code_2024::submit::{Response, Submitter};
+#[cfg(feature = "count-allocations")]
+use advent_of_code_2024::allocations;
+use advent_of_code_2024::runner::{self, InputSource};
+
+#[macro_use]
+extern crate simple_log;
+
+/// Runs the Advent of Code 2024 solutions.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve the given days, or all registered days if none are given
+    Run {
+        days: Vec<u8>,
+
+        /// Only solve this part (1 or 2)
+        #[arg(long)]
+        part: Option<Part>,
+
+        /// Read the input from this file instead of src/day_XX/input.txt, `-` reads from stdin
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Solve the given days (or all) with their input and compare the results with the known answers
+    Verify {
+        days: Vec<u8>,
+    },
+    /// Solve the examples listed in the examples.toml of the given days (or all) and compare the expected answers
+    Examples {
+        days: Vec<u8>,
+    },
+    /// Download the input of the given days (or all) into the cache, inputs that are already cached are not
+    /// downloaded again. Needs the session cookie in AOC_SESSION or .session.
+    Fetch {
+        days: Vec<u8>,
+    },
+    /// Solve one part of a day with its input, submit the answer and record the feedback. Answers known to be
+    /// wrong are not submitted. Needs the session cookie in AOC_SESSION or .session.
+    Submit {
+        day: u8,
+
+        part: Part,
+    },
+    /// Remember the feedback for an answer, e.g. `aoc record 5 2 9985 too-high`
+    Record {
+        day: u8,
+
+        part: Part,
+
+        answer: String,
+
+        /// One of correct, too-high, too-low or wrong
+        feedback: Feedback,
+    },
+    /// Time parsing and both parts of the given days (or all) separately
+    Bench {
+        days: Vec<u8>,
+
+        /// How often each day is run
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Run on a rayon thread pool with this many threads, 1 runs parallel solutions sequentially
+        #[arg(long)]
+        threads: Option<usize>,
+
+        /// Read the input from this file instead of src/day_XX/input.txt, `-` reads from stdin
+        #[arg(long)]
+        input: Option<String>,
+
+        /// Print the results as JSON to stdout
+        #[arg(long)]
+        json: bool,
+    },
+    /// Count the allocations of parsing and both parts of the given days (or all) separately
+    #[cfg(feature = "count-allocations")]
+    Allocations {
+        days: Vec<u8>,
+
+        /// Read the input from this file instead of src/day_XX/input.txt, `-` reads from stdin
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// List all registered days
+    List,
+}
+
+fn main() -> Result<()> {
+    simple_log::quick!("info");
+
+    match Cli::parse().command {
+        Command::Run { days, part, input } => {
+            let days = runner::select_days(&days)?;
+            let parts = part.map_or(Part::BOTH.to_vec(), |part| vec![part]);
+            let input = runner::select_input(&days, input.as_deref())?;
+            runner::run(&days, &parts, &input, &Answers::load(ANSWERS_FILE)?)?;
+        }
+        Command::Verify { days } => {
+            let days = runner::select_days(&days)?;
+            let wrong_results = runner::verify(&days, &Answers::load(ANSWERS_FILE)?)?;
+            if wrong_results > 0 {
+                bail!("{wrong_results} results do not match the known answers")
+            }
+        }
+        Command::Examples { days } => {
+            let days = runner::select_days(&days)?;
+            let wrong_results = examples::check(&days)?;
+            if wrong_results > 0 {
+                bail!("{wrong_results} examples do not match their expected answers")
+            }
+        }
+        Command::Fetch { days } => {
+            let days = runner::select_days(&days)?;
+            let fetcher = Fetcher::new(HttpTransport, http::load_session()?, CACHE_DIR);
+            for day in days {
+                if fetcher.is_cached(day.number) {
+                    info!("Day {:02} input is already cached at {}", day.number, fetcher.input_path(day.number).display());
+                    continue
+                }
+                fetcher.fetch(day.number)?;
+                info!("Day {:02} input downloaded to {}", day.number, fetcher.input_path(day.number).display());
+            }
+        }
+        Command::Submit { day, part } => {
+            let day = runner::select_days(&[day])?[0];
+            let (_, answer) = runner::solve_day(day, &[part], &InputSource::DefaultFile)?.remove(0);
+            let mut answers = Answers::load(ANSWERS_FILE)?;
+            let submitter = Submitter::new(HttpTransport, http::load_session()?);
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+            let response = submitter.submit(day.number, part, &answer, &mut answers, now)?;
+            answers.save(ANSWERS_FILE)?;
+            match response {
+                Response::Feedback(Feedback::Correct) => info!("Day {:02} part {part}: {answer} is correct", day.number),
+                Response::Feedback(feedback) => warn!("Day {:02} part {part}: {answer} is {feedback}", day.number),
+                Response::TooRecently(wait) => warn!("Answer not checked, submitted too recently, wait {wait}s"),
+                Response::WrongLevel => warn!("Day {:02} part {part} is already solved or not unlocked yet", day.number),
+            }
+        }
+        Command::Record { day, part, answer, feedback } => {
+            runner::select_days(&[day])?;
+            let mut answers = Answers::load(ANSWERS_FILE)?;
+            answers.record(day, part, &answer, feedback);
+            answers.save(ANSWERS_FILE)?;
+            info!("Recorded {answer} as {feedback} for day {day:02} part {part}");
+        }
+        Command::Bench { days, iterations, threads, input, json } => {
+            let days = runner::select_days(&days)?;
+            let input = runner::select_input(&days, input.as_deref())?;
+
+            let benchmarks = bench::bench(&days, &input, iterations.max(1), threads)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&benchmarks)?);
+            } else {
+                benchmarks.iter().for_each(bench::log_benchmark);
+            }
+        }
+        #[cfg(feature = "count-allocations")]
+        Command::Allocations { days, input } => {
+            let days = runner::select_days(&days)?;
+            let input = runner::select_input(&days, input.as_deref())?;
+
+            for day in days {
+                if input == InputSource::DefaultFile && !day.has_default_input() {
+                    warn!("Day {:02} skipped, {} does not exist", day.number, day.default_input());
+                    continue
+                }
+                let allocations = allocations::measure_day(day, &input.read(day)?)?;
+                allocations::log_allocations(&allocations);
+            }
+        }
+        Command::List => {
+            for day in advent_of_code_2024::DAYS {
+                info!("Day {:02} (input: {})", day.number, day.default_input());
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//! The format of the `examples.toml` manifests. It is also used by the build script to generate the example
+//! tests, so it must only depend on std and serde.
+
+use std::fmt::{Display, Formatter};
+use serde::Deserialize;
+
+/// The manifest in every day folder that lists the example inputs.
+pub const MANIFEST_FILE: &str = "examples.toml";
+
+/// The expected answer of an example, numbers can be written without quotes in the manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{number}"),
+            Expected::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+/// An example input file together with the answers the puzzle description gives for it. Examples often
+/// only state the answer of one part, so both are optional.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Example {
+    /// File name relative to the day folder
+    pub file: String,
+    pub part_1: Option<Expected>,
+    pub part_2: Option<Expected>,
+}
+
+/// The content of [MANIFEST_FILE], one `[[example]]` table per example.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "example")]
+    pub examples: Vec<Example>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Expected, Manifest};
+
+    #[test]
+    fn parse_manifest() {
+        let manifest: Manifest = toml::from_str(r#"
+            [[example]]
+            file = "test_input.txt"
+            part_1 = 41
+            part_2 = "6"
+
+            [[example]]
+            file = "test_input_simple.txt"
+            part_2 = 3
+        "#).unwrap();
+
+        assert_eq!(manifest.examples.len(), 2);
+        assert_eq!(manifest.examples[0].part_1, Some(Expected::Number(41)));
+        assert_eq!(manifest.examples[0].part_2, Some(Expected::Text("6".to_string())));
+        assert_eq!(manifest.examples[1].part_1, None);
+    }
+}
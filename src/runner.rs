@@ -0,0 +1,186 @@
+use std::any::Any;
+use std::io::{stdin, Read};
+use anyhow::{Result, Context, anyhow, bail};
+use std::path::Path;
+use aoc_utils::solution::{read_input, Part, Solution};
+use crate::answers::{Answers, Verdict};
+use crate::fetch::cached_input;
+use crate::DAYS;
+
+/// Object safe version of [Solution], so days with different input and answer types can be stored
+/// together in [DAYS]. The parsed input is passed around as [Any] and answers are rendered to strings.
+pub trait DaySolution: Sync {
+    fn parse(&self, raw_data: &str) -> Result<Box<dyn Any>>;
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String>;
+}
+
+impl <S> DaySolution for S where S: Solution + Sync, S::Input: 'static {
+    fn parse(&self, raw_data: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(raw_data)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<String> {
+        let input = input.downcast_ref::<S::Input>()
+            .ok_or_else(|| anyhow!("Input was not parsed by this solution"))?;
+        let answer = match part {
+            Part::One => S::part1(input)?,
+            Part::Two => S::part2(input)?,
+        };
+        Ok(answer.to_string())
+    }
+}
+
+/// A registered day together with its solution.
+pub struct Day {
+    pub number: u8,
+    pub solution: &'static dyn DaySolution,
+}
+
+impl Day {
+    /// The folder of the day, containing its input and examples.
+    pub fn directory(&self) -> String {
+        format!("src/day_{:02}", self.number)
+    }
+
+    /// The input file that is used when no explicit input is given: `input.txt` in the day folder if it exists,
+    /// otherwise the input downloaded by `aoc fetch`.
+    pub fn default_input(&self) -> String {
+        let local_input = format!("{}/input.txt", self.directory());
+        if Path::new(&local_input).exists() {
+            local_input
+        } else {
+            cached_input(self.number)
+        }
+    }
+
+    pub fn has_default_input(&self) -> bool {
+        Path::new(&self.default_input()).exists()
+    }
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input file of the day in its folder, see [Day::default_input]
+    DefaultFile,
+    File(String),
+    Stdin,
+}
+
+impl InputSource {
+    /// Create the input source from the command line argument: `-` means stdin, no argument means the default
+    /// file. Stdin is only read when asked for, so runs without a terminal (CI, IDEs) still use the input files.
+    pub fn from_arg(arg: Option<&str>) -> InputSource {
+        match arg {
+            Some("-") => InputSource::Stdin,
+            Some(filename) => InputSource::File(filename.to_string()),
+            None => InputSource::DefaultFile,
+        }
+    }
+
+    /// Read the raw puzzle input for the given day.
+    pub fn read(&self, day: &Day) -> Result<String> {
+        match self {
+            InputSource::DefaultFile => read_input(&day.default_input()),
+            InputSource::File(filename) => read_input(filename),
+            InputSource::Stdin => {
+                let mut raw_data = String::new();
+                stdin().read_to_string(&mut raw_data).context("Could not read input from stdin")?;
+                if raw_data.is_empty() {
+                    bail!("No input was piped into stdin")
+                }
+                Ok(raw_data)
+            }
+        }
+    }
+}
+
+/// Look up the days with the given numbers. If no numbers are given, all registered days are returned.
+pub fn select_days(numbers: &[u8]) -> Result<Vec<&'static Day>> {
+    if numbers.is_empty() {
+        return Ok(DAYS.iter().collect())
+    }
+
+    numbers.iter()
+        .map(|number| {
+            DAYS.iter()
+                .find(|day| day.number == *number)
+                .ok_or_else(|| anyhow!("Day {number} is not registered"))
+        })
+        .collect()
+}
+
+/// The input source for the `--input` argument of a command, see [InputSource::from_arg]. Only the default
+/// input files can be used when more than one day is selected.
+pub fn select_input(days: &[&Day], arg: Option<&str>) -> Result<InputSource> {
+    let input = InputSource::from_arg(arg);
+    if input != InputSource::DefaultFile && days.len() != 1 {
+        bail!("An input file or stdin can only be used with a single day")
+    }
+    Ok(input)
+}
+
+/// Parse the input of a day once and solve the given parts with it.
+pub fn solve_day(day: &Day, parts: &[Part], input: &InputSource) -> Result<Vec<(Part, String)>> {
+    let raw_data = input.read(day)?;
+    let parsed = day.solution.parse(&raw_data)
+        .with_context(|| format!("Day {:02} failed to parse its input", day.number))?;
+
+    parts.iter()
+        .map(|part| {
+            let result = day.solution.solve(parsed.as_ref(), *part)
+                .with_context(|| format!("Day {:02} part {part} failed", day.number))?;
+            Ok((*part, result))
+        })
+        .collect()
+}
+
+/// Solve the given parts of all given days and log the results. Results that are known to be wrong
+/// according to the stored answers are flagged. When more than one day is solved, which is only possible with
+/// the default input files (see [select_input]), days without an input file are skipped.
+pub fn run(days: &[&Day], parts: &[Part], input: &InputSource, answers: &Answers) -> Result<()> {
+    for day in days {
+        if days.len() > 1 && !day.has_default_input() {
+            warn!("Day {:02} skipped, {} does not exist", day.number, day.default_input());
+            continue
+        }
+        for (part, result) in solve_day(day, parts, input)? {
+            let verdict = answers.check(day.number, part, &result);
+            if verdict.is_wrong() {
+                warn!("Day {:02} result part {part}: {result} ({verdict})", day.number);
+            } else {
+                info!("Day {:02} result part {part}: {result}", day.number);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Solve the given days with their default input and compare the results with the stored answers.
+/// Days without an input file are skipped. Returns the number of results that are wrong.
+pub fn verify(days: &[&Day], answers: &Answers) -> Result<usize> {
+    let mut wrong_results = 0;
+
+    for day in days {
+        if !day.has_default_input() {
+            warn!("Day {:02} skipped, {} does not exist", day.number, day.default_input());
+            continue
+        }
+
+        for (part, result) in solve_day(day, &Part::BOTH, &InputSource::DefaultFile)? {
+            let verdict = answers.check(day.number, part, &result);
+            match verdict {
+                Verdict::Correct => info!("Day {:02} part {part}: {result} ({verdict})", day.number),
+                Verdict::Unknown => warn!("Day {:02} part {part}: {result} (no known answer)", day.number),
+                _ => {
+                    error!("Day {:02} part {part}: {result} ({verdict})", day.number);
+                    wrong_results += 1;
+                }
+            }
+        }
+    }
+
+    Ok(wrong_results)
+}
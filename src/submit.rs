@@ -0,0 +1,211 @@
+use anyhow::{Result, bail};
+use aoc_utils::solution::Part;
+use crate::answers::{Answers, Feedback, Verdict};
+use crate::http::{require_session, Transport, BASE_URL};
+
+/// How long to wait after a rejected answer if the response does not say, Advent of Code locks out for at
+/// least a minute.
+pub const DEFAULT_WAIT_SECS: u64 = 60;
+
+/// What Advent of Code responded to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    /// The answer was checked
+    Feedback(Feedback),
+    /// The answer was not checked because the last one was submitted too recently, with the seconds left to wait
+    TooRecently(u64),
+    /// The part is either already solved or not unlocked yet
+    WrongLevel,
+}
+
+impl Response {
+    /// Understand the HTML page that is returned after submitting an answer.
+    pub fn parse(body: &str) -> Result<Response> {
+        if body.contains("That's the right answer") {
+            return Ok(Response::Feedback(Feedback::Correct))
+        }
+        if body.contains("That's not the right answer") {
+            let feedback = if body.contains("your answer is too high") {
+                Feedback::TooHigh
+            } else if body.contains("your answer is too low") {
+                Feedback::TooLow
+            } else {
+                Feedback::Wrong
+            };
+            return Ok(Response::Feedback(feedback))
+        }
+        if body.contains("You gave an answer too recently") {
+            return Ok(Response::TooRecently(parse_wait(body).unwrap_or(DEFAULT_WAIT_SECS)))
+        }
+        if body.contains("You don't seem to be solving the right level") {
+            return Ok(Response::WrongLevel)
+        }
+        bail!("Could not understand the response to the submitted answer")
+    }
+}
+
+/// Find how many seconds the page asks to wait, either as "You have 4m 12s left to wait" or as "Please wait
+/// 5 minutes before trying again". None if the page does not say.
+pub fn parse_wait(body: &str) -> Option<u64> {
+    if let Some((_, rest)) = body.split_once("You have ") {
+        let (wait, _) = rest.split_once(" left to wait")?;
+        return wait.split_whitespace()
+            .map(|amount| {
+                let unit_start = amount.find(|c: char| !c.is_ascii_digit())?;
+                let number: u64 = amount[..unit_start].parse().ok()?;
+                match &amount[unit_start..] {
+                    "h" => Some(number * 3600),
+                    "m" => Some(number * 60),
+                    "s" => Some(number),
+                    _ => None,
+                }
+            })
+            .sum()
+    }
+
+    let (_, rest) = body.split_once("wait ")?;
+    let (wait, _) = rest.split_once(" before trying again")?;
+    let (number, unit) = wait.split_once(' ')?;
+    let number = match number {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    match unit {
+        "minute" | "minutes" => Some(number * 60),
+        "second" | "seconds" => Some(number),
+        _ => None,
+    }
+}
+
+/// Submits answers and keeps the answers store up to date with the feedback. Answers that are known to
+/// be wrong are never submitted and after a rejection it waits as long as the response asked for.
+pub struct Submitter<T: Transport> {
+    transport: T,
+    session: Option<String>,
+}
+
+impl <T: Transport> Submitter<T> {
+    pub fn new(transport: T, session: Option<String>) -> Submitter<T> {
+        Submitter { transport, session }
+    }
+
+    /// Submit the answer of a part and record the feedback. `now` is the current time in seconds since the
+    /// unix epoch.
+    pub fn submit(&self, day: u8, part: Part, answer: &str, answers: &mut Answers, now: u64) -> Result<Response> {
+        match answers.check(day, part, answer) {
+            Verdict::Unknown => {}
+            Verdict::Correct => bail!("Day {day:02} part {part} is already solved with {answer}"),
+            verdict => bail!("Refusing to submit {answer} for day {day:02} part {part}, it is {verdict}"),
+        }
+
+        let retry_at = answers.get(day, part).and_then(|part_answers| part_answers.retry_at);
+        if let Some(retry_at) = retry_at.filter(|retry_at| now < *retry_at) {
+            bail!("The last answer for day {day:02} part {part} was not accepted, wait {}s before submitting again", retry_at - now)
+        }
+
+        let session = require_session(self.session.as_deref())?;
+        let level = part.to_string();
+        let body = self.transport.post_form(
+            &format!("{BASE_URL}/day/{day}/answer"),
+            session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+
+        let response = Response::parse(&body)?;
+        let retry_at = match response {
+            Response::Feedback(Feedback::Correct) => None,
+            Response::Feedback(_) => Some(now + parse_wait(&body).unwrap_or(DEFAULT_WAIT_SECS)),
+            Response::TooRecently(wait) => Some(now + wait),
+            Response::WrongLevel => retry_at,
+        };
+        if let Response::Feedback(feedback) = response {
+            answers.record(day, part, answer, feedback);
+        }
+        answers.get_mut(day, part).retry_at = retry_at;
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_utils::solution::Part;
+    use crate::answers::{Answers, Feedback, Verdict};
+    use crate::http::FixtureTransport;
+    use super::{parse_wait, Response, Submitter};
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+
+    const TOO_RECENTLY: &str = "<article><p>You gave an answer too recently; you have to wait after submitting \
+        an answer before trying again.  You have 4m 12s left to wait.</p></article>";
+
+    #[test]
+    fn parse_responses() {
+        assert_eq!(Response::parse("<p>That's the right answer! You are one gold star closer.</p>").unwrap(),
+                   Response::Feedback(Feedback::Correct));
+        assert_eq!(Response::parse(TOO_HIGH).unwrap(), Response::Feedback(Feedback::TooHigh));
+        assert_eq!(Response::parse("<p>That's not the right answer; your answer is too low.</p>").unwrap(),
+                   Response::Feedback(Feedback::TooLow));
+        assert_eq!(Response::parse("<p>That's not the right answer. If you're stuck...</p>").unwrap(),
+                   Response::Feedback(Feedback::Wrong));
+        assert_eq!(Response::parse("<p>You gave an answer too recently. You have 34s left to wait.</p>").unwrap(),
+                   Response::TooRecently(34));
+        assert_eq!(Response::parse(TOO_RECENTLY).unwrap(), Response::TooRecently(252));
+        assert!(Response::parse("<html>Something else</html>").is_err());
+    }
+
+    #[test]
+    fn parse_waits() {
+        assert_eq!(parse_wait(TOO_HIGH), Some(60));
+        assert_eq!(parse_wait(TOO_RECENTLY), Some(252));
+        assert_eq!(parse_wait("<p>That's not the right answer. Please wait 5 minutes before trying again.</p>"), Some(300));
+        assert_eq!(parse_wait("<p>That's not the right answer.</p>"), None);
+    }
+
+    #[test]
+    fn record_feedback_and_wait_after_rejection() {
+        let transport = FixtureTransport::new(TOO_HIGH);
+        let submitter = Submitter::new(&transport, Some("secret".to_string()));
+        let mut answers = Answers::default();
+
+        let response = submitter.submit(5, Part::Two, "9985", &mut answers, 1000).unwrap();
+        assert_eq!(response, Response::Feedback(Feedback::TooHigh));
+        assert_eq!(answers.check(5, Part::Two, "9985"), Verdict::KnownWrong(Feedback::TooHigh));
+        assert_eq!(*transport.requests.borrow(), vec!["https://adventofcode.com/2024/day/5/answer?level=2&answer=9985"]);
+
+        let error = submitter.submit(5, Part::Two, "5000", &mut answers, 1030).unwrap_err();
+        assert!(error.to_string().contains("wait 30s"));
+        assert!(submitter.submit(5, Part::Two, "5000", &mut answers, 1060).is_ok());
+        assert_eq!(transport.requests.borrow().len(), 2);
+    }
+
+    #[test]
+    fn wait_as_long_as_asked_after_submitting_too_recently() {
+        let transport = FixtureTransport::new(TOO_RECENTLY);
+        let submitter = Submitter::new(&transport, Some("secret".to_string()));
+        let mut answers = Answers::default();
+
+        let response = submitter.submit(5, Part::Two, "9985", &mut answers, 1000).unwrap();
+        assert_eq!(response, Response::TooRecently(252));
+        assert_eq!(answers.get(5, Part::Two).unwrap().retry_at, Some(1252));
+        assert_eq!(answers.check(5, Part::Two, "9985"), Verdict::Unknown);
+
+        let error = submitter.submit(5, Part::Two, "9985", &mut answers, 1200).unwrap_err();
+        assert!(error.to_string().contains("wait 52s"));
+        assert_eq!(transport.requests.borrow().len(), 1);
+    }
+
+    #[test]
+    fn refuse_known_wrong_answers() {
+        let transport = FixtureTransport::new(TOO_HIGH);
+        let submitter = Submitter::new(&transport, Some("secret".to_string()));
+        let mut answers = Answers::default();
+        answers.record(5, Part::Two, "9985", Feedback::TooHigh);
+        answers.record(5, Part::One, "143", Feedback::Correct);
+
+        assert!(submitter.submit(5, Part::Two, "9985", &mut answers, 0).is_err());
+        assert!(submitter.submit(5, Part::Two, "10000", &mut answers, 0).is_err());
+        assert!(submitter.submit(5, Part::One, "143", &mut answers, 0).is_err());
+        assert!(transport.requests.borrow().is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use anyhow::{Result, bail};
+use aoc_utils::solution::Solution;
+
+#[derive(Debug)]
+pub struct Input {
+
+}
+
+mod parse {
+    use aoc_parse::{parser, prelude::*};
+    use anyhow::{Result, Context};
+    use super::Input;
+
+    pub fn parse_input(raw_data: &str) -> Result<Input> {
+        let parser = parser!(lines(""));
+
+        let raw_parsed = parser.parse(raw_data).context("parse error")?;
+
+        Ok(Input {
+
+        })
+    }
+}
+
+pub struct Day{{ cookiecutter.day }};
+
+impl Solution for Day{{ cookiecutter.day }} {
+    type Input = Input;
+    type Answer = u32;
+
+    fn parse(raw_data: &str) -> Result<Input> {
+        parse::parse_input(raw_data)
+    }
+
+    fn part1(input: &Input) -> Result<u32> {
+        debug!("{:?}", input);
+
+        bail!("part 1 is not solved yet")
+    }
+
+    fn part2(input: &Input) -> Result<u32> {
+        debug!("{:?}", input);
+
+        bail!("part 2 is not solved yet")
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::Day{{ cookiecutter.day }};
+
+    aoc_utils::aoc_tests!(Day{{ cookiecutter.day }});
+}
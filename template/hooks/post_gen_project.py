@@ -3,21 +3,22 @@ import sys
 
 generated_root = os.getcwd()  # this is the folder of "cookiecutter.project_slug"
 
-cargo_toml = os.path.join(generated_root, '..', '..', 'Cargo.toml')
+lib_rs = os.path.join(generated_root, '..', 'lib.rs')
+marker = '    // new days are inserted above this line by the template hook\n'
 
 try:
-    with open(cargo_toml, 'a+') as file:
-        file.seek(0, 2)  # Move to the end of the file
-        if file.tell() == 0:
-            raise FileNotFoundError
-        # Append to the file here
-        file.writelines([
-            '\n',
-            '[[bin]]\n',
-            'name = "day_{{ cookiecutter.day }}"\n',
-            'path = "src/day_{{ cookiecutter.day }}/main.rs"\n'
-        ])
+    with open(lib_rs, 'r') as file:
+        content = file.read()
+
+    if marker not in content:
+        print(f"Could not find the days! marker in {lib_rs}")
+        sys.exit(1)
+
+    # register the new day right before the marker so it gets a module and an entry in DAYS
+    day_entry = '    {{ cookiecutter.day | int }} => day_{{ cookiecutter.day }}::Day{{ cookiecutter.day }},\n'
+    with open(lib_rs, 'w') as file:
+        file.write(content.replace(marker, day_entry + marker))
 
 except FileNotFoundError:
-    print(f"Could not find Cargo.toml at {cargo_toml}")
-    sys.exit(1)
\ No newline at end of file
+    print(f"Could not find lib.rs at {lib_rs}")
+    sys.exit(1)